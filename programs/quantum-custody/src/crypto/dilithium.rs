@@ -0,0 +1,578 @@
+//! CRYSTALS-Dilithium (NIST round 3) signature verification
+//!
+//! Verification-only port of the round 3 reference implementation as packaged by
+//! PQClean (64-byte `tr`, per-level challenge seed), which is what the SDK's
+//! `pqcrypto-dilithium` 0.5 signs with. This is not FIPS 204 ML-DSA: the final
+//! standard changed the message and challenge encoding, so ML-DSA signatures
+//! will not verify here.
+//!
+//! A full verification costs roughly 3.9M (Dilithium2), 6.7M (Dilithium3) and
+//! 11.4M (Dilithium5) compute units, far over the 1.4M per-transaction cap, so
+//! it is split into `start`, `Params::total_steps` calls to `step` and `finish`,
+//! driven across transactions by a `VerificationSession`. `start` costs
+//! 250-420k CU, each matrix entry step about 160k and each row completion about
+//! 250k, with under 4KB of stack and 2KB of heap throughout.
+
+use anchor_lang::prelude::*;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+
+const N: usize = 256;
+const Q: i32 = 8380417;
+const QINV: i32 = 58728449;
+const D: u32 = 13;
+const ROOT_OF_UNITY: i64 = 1753;
+const MONT: i64 = 4193792; // 2^32 mod Q
+const SEED_BYTES: usize = 32;
+const TR_BYTES: usize = 64;
+const CRH_BYTES: usize = 64;
+const POLYT1_PACKED_BYTES: usize = 320;
+const SHAKE128_RATE: usize = 168;
+/// Packed w1 of Dilithium5: k = 8 rows of 4-bit coefficients
+const MAX_W1_PACKED_BYTES: usize = 8 * N * 4 / 8;
+/// A Dilithium2 w1 row, packed with 6 bits per coefficient
+const MAX_W1_ROW_BYTES: usize = N * 6 / 8;
+
+pub struct Params {
+    pub k: usize,
+    pub l: usize,
+    pub tau: usize,
+    pub beta: i32,
+    pub gamma1: i32,
+    pub gamma2: i32,
+    pub omega: usize,
+    pub c_tilde_bytes: usize,
+}
+
+pub const DILITHIUM2: Params = Params {
+    k: 4,
+    l: 4,
+    tau: 39,
+    beta: 78,
+    gamma1: 1 << 17,
+    gamma2: (Q - 1) / 88,
+    omega: 80,
+    c_tilde_bytes: 32,
+};
+
+pub const DILITHIUM3: Params = Params {
+    k: 6,
+    l: 5,
+    tau: 49,
+    beta: 196,
+    gamma1: 1 << 19,
+    gamma2: (Q - 1) / 32,
+    omega: 55,
+    c_tilde_bytes: 48,
+};
+
+pub const DILITHIUM5: Params = Params {
+    k: 8,
+    l: 7,
+    tau: 60,
+    beta: 120,
+    gamma1: 1 << 19,
+    gamma2: (Q - 1) / 32,
+    omega: 75,
+    c_tilde_bytes: 64,
+};
+
+impl Params {
+    fn z_bits(&self) -> usize {
+        if self.gamma1 == 1 << 17 { 18 } else { 20 }
+    }
+
+    fn w1_bits(&self) -> usize {
+        if self.gamma2 == (Q - 1) / 88 { 6 } else { 4 }
+    }
+
+    pub fn public_key_bytes(&self) -> usize {
+        SEED_BYTES + self.k * POLYT1_PACKED_BYTES
+    }
+
+    pub fn signature_bytes(&self) -> usize {
+        self.c_tilde_bytes + self.l * N * self.z_bits() / 8 + self.omega + self.k
+    }
+}
+
+/// Verification state carried between steps.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Progress {
+    pub mu: [u8; CRH_BYTES],
+    /// Row of w' = A*z - c*t1*2^d being accumulated, in the NTT domain
+    pub acc: Vec<i32>,
+    /// Packed high bits of the rows of w' completed so far
+    pub w1: Vec<u8>,
+}
+
+impl Progress {
+    pub const SPACE: usize = CRH_BYTES + 4 + N * 4 + 4 + MAX_W1_PACKED_BYTES;
+}
+
+impl Params {
+    fn w1_packed_bytes(&self) -> usize {
+        N * self.w1_bits() / 8
+    }
+
+    /// One step per entry of the k x l matrix A, plus one per row to fold in
+    /// c*t1 and recover that row's w1.
+    pub fn total_steps(&self) -> u32 {
+        (self.k * (self.l + 1)) as u32
+    }
+}
+
+/// Checks the encoding and norm bounds of `signature` and computes the message
+/// representative mu, producing the initial `Progress`. Returns `None` if the
+/// signature is malformed.
+pub fn start(params: &Params, public_key: &[u8], message: &[u8], signature: &[u8]) -> Option<Progress> {
+    if public_key.len() != params.public_key_bytes()
+        || signature.len() != params.signature_bytes()
+    {
+        return None;
+    }
+
+    let (_, z_bytes, hint_bytes) = split_signature(params, signature);
+    if !unpack_hint(params, hint_bytes, |_, _| {}) {
+        return None;
+    }
+
+    let mut z = [0i32; N];
+    for i in 0..params.l {
+        unpack_z(params, z_bytes, i, &mut z);
+        if z.iter().any(|coeff| coeff.abs() >= params.gamma1 - params.beta) {
+            return None;
+        }
+    }
+
+    // mu = CRH(H(pk) || M)
+    let mut tr = [0u8; TR_BYTES];
+    shake256(&[public_key], &mut tr);
+    let mut mu = [0u8; CRH_BYTES];
+    shake256(&[&tr, message], &mut mu);
+
+    Some(Progress {
+        mu,
+        acc: vec![0i32; N],
+        w1: Vec::with_capacity(params.k * params.w1_packed_bytes()),
+    })
+}
+
+/// Performs verification step number `step` (see `Params::total_steps`):
+/// either adds A[i][j]*z[j] into the current row, or completes row i.
+pub fn step(params: &Params, public_key: &[u8], signature: &[u8], step: u32, progress: &mut Progress) {
+    let row = step as usize / (params.l + 1);
+    let column = step as usize % (params.l + 1);
+
+    if column < params.l {
+        accumulate_entry(params, public_key, signature, row, column, progress);
+    } else {
+        complete_row(params, public_key, signature, row, progress);
+    }
+}
+
+/// After the final step, checks that the challenge recomputed from mu and w1
+/// matches the one in `signature`.
+pub fn finish(params: &Params, signature: &[u8], progress: &Progress) -> bool {
+    let (c_tilde, _, _) = split_signature(params, signature);
+
+    let mut c_tilde_prime = [0u8; 64];
+    shake256(&[&progress.mu, &progress.w1], &mut c_tilde_prime[..params.c_tilde_bytes]);
+
+    progress.w1.len() == params.k * params.w1_packed_bytes()
+        && c_tilde_prime[..params.c_tilde_bytes] == *c_tilde
+}
+
+/// Returns `true` if `signature` is a valid detached signature of `message`
+/// under `public_key`, running every step at once. Too expensive for a single
+/// instruction on-chain; see `VerificationSession`.
+pub fn verify(params: &Params, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(mut progress) = start(params, public_key, message, signature) else {
+        return false;
+    };
+    for n in 0..params.total_steps() {
+        step(params, public_key, signature, n, &mut progress);
+    }
+    finish(params, signature, &progress)
+}
+
+fn split_signature<'a>(params: &Params, signature: &'a [u8]) -> (&'a [u8], &'a [u8], &'a [u8]) {
+    let (c_tilde, rest) = signature.split_at(params.c_tilde_bytes);
+    let (z_bytes, hint_bytes) = rest.split_at(params.l * N * params.z_bits() / 8);
+    (c_tilde, z_bytes, hint_bytes)
+}
+
+fn unpack_z(params: &Params, z_bytes: &[u8], index: usize, out: &mut [i32; N]) {
+    let poly_bytes = N * params.z_bits() / 8;
+    unpack_bits(&z_bytes[index * poly_bytes..(index + 1) * poly_bytes], params.z_bits(), out);
+    for coeff in out.iter_mut() {
+        *coeff = params.gamma1 - *coeff;
+    }
+}
+
+fn accumulate_entry(
+    params: &Params,
+    public_key: &[u8],
+    signature: &[u8],
+    row: usize,
+    column: usize,
+    progress: &mut Progress,
+) {
+    let (_, z_bytes, _) = split_signature(params, signature);
+
+    let mut z = [0i32; N];
+    unpack_z(params, z_bytes, column, &mut z);
+    ntt(&mut z);
+
+    let mut entry = [0i32; N];
+    expand_matrix_entry(&public_key[..SEED_BYTES], row, column, &mut entry);
+
+    if column == 0 {
+        progress.acc.fill(0);
+    }
+    for ((a, t), zc) in progress.acc.iter_mut().zip(entry.iter()).zip(z.iter()) {
+        *a += montgomery_reduce(*t as i64 * *zc as i64);
+    }
+}
+
+fn complete_row(params: &Params, public_key: &[u8], signature: &[u8], row: usize, progress: &mut Progress) {
+    let (c_tilde, _, hint_bytes) = split_signature(params, signature);
+
+    let mut c = [0i32; N];
+    // Only the first SEED_BYTES of c~ seed the challenge polynomial
+    sample_in_ball(params, &c_tilde[..SEED_BYTES], &mut c);
+    ntt(&mut c);
+
+    let t1_bytes = &public_key[SEED_BYTES..];
+    let mut t1 = [0i32; N];
+    unpack_bits(&t1_bytes[row * POLYT1_PACKED_BYTES..(row + 1) * POLYT1_PACKED_BYTES], 10, &mut t1);
+    for coeff in t1.iter_mut() {
+        *coeff <<= D;
+    }
+    ntt(&mut t1);
+
+    let acc = &mut progress.acc;
+    for ((a, t), cc) in acc.iter_mut().zip(t1.iter()).zip(c.iter()) {
+        *a = reduce32(*a - montgomery_reduce(*t as i64 * *cc as i64));
+    }
+    invntt_tomont(acc);
+
+    let mut hint = [0u8; N];
+    unpack_hint(params, hint_bytes, |hint_row, index| {
+        if hint_row == row {
+            hint[index] = 1;
+        }
+    });
+
+    // t1 is no longer needed, so its space holds this row of w1
+    let w1 = &mut t1;
+    for ((w, a), h) in w1.iter_mut().zip(acc.iter()).zip(hint.iter()) {
+        *w = use_hint(params, caddq(*a), *h);
+    }
+    let mut packed = [0u8; MAX_W1_ROW_BYTES];
+    let packed = &mut packed[..params.w1_packed_bytes()];
+    pack_bits(w1, params.w1_bits(), packed);
+    progress.w1.extend_from_slice(packed);
+}
+
+fn shake256(inputs: &[&[u8]], out: &mut [u8]) {
+    let mut hasher = Shake256::default();
+    for input in inputs {
+        hasher.update(input);
+    }
+    hasher.finalize_xof().read(out);
+}
+
+/// Decodes the hint, calling `visit(row, index)` for each set coefficient.
+/// Returns `false` for any non-canonical encoding.
+fn unpack_hint(params: &Params, bytes: &[u8], mut visit: impl FnMut(usize, usize)) -> bool {
+    let (indices, counts) = bytes.split_at(params.omega);
+    let mut k = 0usize;
+
+    for (i, &count) in counts.iter().enumerate() {
+        let count = count as usize;
+        if count < k || count > params.omega {
+            return false;
+        }
+        for j in k..count {
+            // Indices must be strictly increasing for strong unforgeability
+            if j > k && indices[j] <= indices[j - 1] {
+                return false;
+            }
+            visit(i, indices[j] as usize);
+        }
+        k = count;
+    }
+
+    indices[k..].iter().all(|&b| b == 0)
+}
+
+fn unpack_bits(bytes: &[u8], bits: usize, out: &mut [i32]) {
+    let mask = (1u32 << bits) - 1;
+    for (i, coeff) in out.iter_mut().enumerate() {
+        let bit = i * bits;
+        let mut word = 0u32;
+        for (n, byte) in bytes[bit / 8..].iter().take((bit % 8 + bits).div_ceil(8)).enumerate() {
+            word |= (*byte as u32) << (8 * n);
+        }
+        *coeff = ((word >> (bit % 8)) & mask) as i32;
+    }
+}
+
+fn pack_bits(coeffs: &[i32], bits: usize, out: &mut [u8]) {
+    out.fill(0);
+    for (i, &coeff) in coeffs.iter().enumerate() {
+        let bit = i * bits;
+        let value = (coeff as u32) << (bit % 8);
+        for (n, byte) in out[bit / 8..].iter_mut().take((bit % 8 + bits).div_ceil(8)).enumerate() {
+            *byte |= (value >> (8 * n)) as u8;
+        }
+    }
+}
+
+fn expand_matrix_entry(rho: &[u8], row: usize, column: usize, out: &mut [i32; N]) {
+    let mut hasher = Shake128::default();
+    hasher.update(rho);
+    hasher.update(&[column as u8, row as u8]);
+    let mut reader = hasher.finalize_xof();
+
+    // Rejection-sample 23-bit values below Q, a SHAKE128 block at a time
+    let mut filled = 0;
+    let mut block = [0u8; SHAKE128_RATE];
+    while filled < N {
+        reader.read(&mut block);
+        for buf in block.chunks_exact(3) {
+            let t = (buf[0] as i32) | ((buf[1] as i32) << 8) | (((buf[2] & 0x7F) as i32) << 16);
+            if t < Q && filled < N {
+                out[filled] = t;
+                filled += 1;
+            }
+        }
+    }
+}
+
+fn sample_in_ball(params: &Params, seed: &[u8], c: &mut [i32]) {
+    let mut hasher = Shake256::default();
+    hasher.update(seed);
+    let mut reader = hasher.finalize_xof();
+
+    let mut sign_bytes = [0u8; 8];
+    reader.read(&mut sign_bytes);
+    let mut signs = u64::from_le_bytes(sign_bytes);
+
+    c.fill(0);
+    let mut byte = [0u8; 1];
+    for i in N - params.tau..N {
+        let b = loop {
+            reader.read(&mut byte);
+            if byte[0] as usize <= i {
+                break byte[0] as usize;
+            }
+        };
+        c[i] = c[b];
+        c[b] = 1 - 2 * (signs & 1) as i32;
+        signs >>= 1;
+    }
+}
+
+fn use_hint(params: &Params, a: i32, hint: u8) -> i32 {
+    let (a0, a1) = decompose(params, a);
+    if hint == 0 {
+        return a1;
+    }
+
+    if params.gamma2 == (Q - 1) / 32 {
+        if a0 > 0 { (a1 + 1) & 15 } else { (a1 - 1) & 15 }
+    } else if a0 > 0 {
+        if a1 == 43 { 0 } else { a1 + 1 }
+    } else if a1 == 0 {
+        43
+    } else {
+        a1 - 1
+    }
+}
+
+fn decompose(params: &Params, a: i32) -> (i32, i32) {
+    let mut a1 = (a + 127) >> 7;
+    if params.gamma2 == (Q - 1) / 32 {
+        a1 = (a1 * 1025 + (1 << 21)) >> 22;
+        a1 &= 15;
+    } else {
+        a1 = (a1 * 11275 + (1 << 23)) >> 24;
+        a1 ^= ((43 - a1) >> 31) & a1;
+    }
+
+    let mut a0 = a - a1 * 2 * params.gamma2;
+    a0 -= (((Q - 1) / 2 - a0) >> 31) & Q;
+    (a0, a1)
+}
+
+fn montgomery_reduce(a: i64) -> i32 {
+    let t = (a as i32).wrapping_mul(QINV);
+    ((a - t as i64 * Q as i64) >> 32) as i32
+}
+
+fn reduce32(a: i32) -> i32 {
+    let t = (a + (1 << 22)) >> 23;
+    a - t * Q
+}
+
+fn caddq(a: i32) -> i32 {
+    a + ((a >> 31) & Q)
+}
+
+fn ntt(a: &mut [i32]) {
+    let mut k = 0;
+    let mut len = 128;
+    while len > 0 {
+        let mut start = 0;
+        while start < N {
+            k += 1;
+            let zeta = ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = montgomery_reduce(zeta * a[j + len] as i64);
+                a[j + len] = a[j] - t;
+                a[j] += t;
+            }
+            start += 2 * len;
+        }
+        len >>= 1;
+    }
+}
+
+fn invntt_tomont(a: &mut [i32]) {
+    const F: i64 = 41978; // mont^2 / 256
+
+    let mut k = N;
+    let mut len = 1;
+    while len < N {
+        let mut start = 0;
+        while start < N {
+            k -= 1;
+            let zeta = -ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = t + a[j + len];
+                a[j + len] = montgomery_reduce(zeta * (t - a[j + len]) as i64);
+            }
+            start += 2 * len;
+        }
+        len <<= 1;
+    }
+
+    for coeff in a.iter_mut() {
+        *coeff = montgomery_reduce(F * *coeff as i64);
+    }
+}
+
+/// Powers of the 512th root of unity in Montgomery form, in bit-reversed order.
+const ZETAS: [i32; N] = compute_zetas();
+
+const fn compute_zetas() -> [i32; N] {
+    let q = Q as i64;
+    let mut zetas = [0i32; N];
+    let mut i = 1;
+    while i < N {
+        let mut exponent = (i as u8).reverse_bits();
+        let mut base = ROOT_OF_UNITY;
+        let mut power = MONT;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                power = power * base % q;
+            }
+            base = base * base % q;
+            exponent >>= 1;
+        }
+        zetas[i] = if power > q / 2 { (power - q) as i32 } else { power as i32 };
+        i += 1;
+    }
+    zetas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::kat::parse;
+    use pqcrypto_dilithium::{dilithium2, dilithium3, dilithium5};
+    use pqcrypto_traits::sign::{DetachedSignature, PublicKey};
+
+    const KATS: [(&Params, &str); 3] = [
+        (&DILITHIUM2, include_str!("testdata/dilithium2.kat")),
+        (&DILITHIUM3, include_str!("testdata/dilithium3.kat")),
+        (&DILITHIUM5, include_str!("testdata/dilithium5.kat")),
+    ];
+
+    #[test]
+    fn test_known_answer_vectors() {
+        for (params, kat) in KATS {
+            let (message, public_key, signature) = parse(kat);
+            assert_eq!(public_key.len(), params.public_key_bytes());
+            assert_eq!(signature.len(), params.signature_bytes());
+            assert!(verify(params, &public_key, &message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_progress_survives_serialization() {
+        for (params, kat) in KATS {
+            let (message, public_key, signature) = parse(kat);
+            let mut progress = start(params, &public_key, &message, &signature).unwrap();
+            for n in 0..params.total_steps() {
+                // Each step runs against the state as reloaded from the session account
+                let bytes = progress.try_to_vec().unwrap();
+                assert!(bytes.len() <= Progress::SPACE);
+                progress = Progress::try_from_slice(&bytes).unwrap();
+                step(params, &public_key, &signature, n, &mut progress);
+            }
+            assert!(progress.try_to_vec().unwrap().len() <= Progress::SPACE);
+            assert!(finish(params, &signature, &progress));
+        }
+    }
+
+    #[test]
+    fn test_sdk_signatures_verify() {
+        let message = b"quantum-custody sign_transaction";
+
+        let (pk, sk) = dilithium2::keypair();
+        let sig = dilithium2::detached_sign(message, &sk);
+        assert!(verify(&DILITHIUM2, pk.as_bytes(), message, sig.as_bytes()));
+
+        let (pk, sk) = dilithium3::keypair();
+        let sig = dilithium3::detached_sign(message, &sk);
+        assert!(verify(&DILITHIUM3, pk.as_bytes(), message, sig.as_bytes()));
+
+        let (pk, sk) = dilithium5::keypair();
+        let sig = dilithium5::detached_sign(message, &sk);
+        assert!(verify(&DILITHIUM5, pk.as_bytes(), message, sig.as_bytes()));
+    }
+
+    #[test]
+    fn test_rejects_tampered_inputs() {
+        for (params, kat) in KATS {
+            let (message, public_key, signature) = parse(kat);
+
+            // Walk through c~, z and the hint with a different bit each time
+            for (n, index) in (0..signature.len()).step_by(61).enumerate() {
+                let mut tampered = signature.clone();
+                tampered[index] ^= 1 << (n % 8);
+                assert!(!verify(params, &public_key, &message, &tampered), "sig byte {index}");
+            }
+            for index in [0, SEED_BYTES - 1, SEED_BYTES, public_key.len() - 1] {
+                let mut tampered = public_key.clone();
+                tampered[index] ^= 0x80;
+                assert!(!verify(params, &tampered, &message, &signature), "pk byte {index}");
+            }
+            for index in [0, message.len() - 1] {
+                let mut tampered = message.clone();
+                tampered[index] ^= 0x01;
+                assert!(!verify(params, &public_key, &tampered, &signature), "msg byte {index}");
+            }
+
+            assert!(!verify(params, &public_key, &message, &signature[1..]));
+            assert!(!verify(params, &public_key, &message[1..], &signature));
+        }
+
+        // A valid signature under one parameter set means nothing under another
+        let (message, public_key, signature) = parse(KATS[1].1);
+        assert!(!verify(&DILITHIUM5, &public_key, &message, &signature));
+    }
+}
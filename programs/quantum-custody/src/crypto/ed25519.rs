@@ -0,0 +1,68 @@
+//! Ed25519 signature checks through the native Ed25519 precompile
+//!
+//! The program cannot verify Ed25519 signatures itself within its compute
+//! budget, so the transaction carries an Ed25519 program instruction and this
+//! module inspects it through the instructions sysvar. The runtime rejects the
+//! whole transaction if the precompile's signatures are invalid, so finding a
+//! matching entry is enough.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBLIC_KEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// Returns `true` if an Ed25519 program instruction earlier in this
+/// transaction verifies a signature by `signer` over exactly `message`.
+/// Only entries whose key, signature and message live in the precompile
+/// instruction's own data are considered.
+pub fn is_signed(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<bool> {
+    let current = load_current_index_checked(instructions)? as usize;
+
+    for index in 0..current {
+        let instruction = load_instruction_at_checked(index, instructions)?;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+        if contains_signature(&instruction.data, signer, message) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn contains_signature(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    let count = match data.first() {
+        Some(&count) => count as usize,
+        None => return false,
+    };
+
+    (0..count).any(|i| {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+        let offsets = match data.get(start..start + SIGNATURE_OFFSETS_SIZE) {
+            Some(offsets) => offsets,
+            None => return false,
+        };
+        let field = |n: usize| u16::from_le_bytes([offsets[2 * n], offsets[2 * n + 1]]);
+
+        // Signature, public key and message must all come from this instruction
+        if field(1) != u16::MAX || field(3) != u16::MAX || field(6) != u16::MAX {
+            return false;
+        }
+
+        let public_key_offset = field(2) as usize;
+        let message_offset = field(4) as usize;
+        let message_size = field(5) as usize;
+
+        data.get(field(0) as usize..field(0) as usize + SIGNATURE_SIZE).is_some()
+            && data.get(public_key_offset..public_key_offset + PUBLIC_KEY_SIZE)
+                == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message)
+    })
+}
@@ -0,0 +1,16 @@
+//! NIST known-answer vectors under `testdata/`, trimmed to the first record.
+
+/// Parses a `.kat` file into (message, public key, signature).
+pub fn parse(kat: &str) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let field = |name: &str| -> Vec<u8> {
+        let hex = kat
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(" = "))
+            .unwrap();
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    };
+    (field("msg"), field("pk"), field("sig"))
+}
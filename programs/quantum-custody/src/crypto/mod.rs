@@ -0,0 +1,186 @@
+pub mod dilithium;
+pub mod ed25519;
+pub mod sphincs;
+#[cfg(test)]
+mod kat;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::{
+    CryptoAlgorithm, QuantumVault, SessionGrant, SessionKey, SignatureBuffer, SignaturePolicy,
+    SigningDomain, VaultAction, VerificationSession,
+};
+use crate::error::QuantumCustodyError;
+
+pub fn dilithium_params(algorithm: CryptoAlgorithm) -> Option<&'static dilithium::Params> {
+    match algorithm {
+        CryptoAlgorithm::Dilithium2 => Some(&dilithium::DILITHIUM2),
+        CryptoAlgorithm::Dilithium3 => Some(&dilithium::DILITHIUM3),
+        CryptoAlgorithm::Dilithium5 => Some(&dilithium::DILITHIUM5),
+        _ => None,
+    }
+}
+
+pub fn sphincs_params(algorithm: CryptoAlgorithm) -> Option<&'static sphincs::Params> {
+    match algorithm {
+        CryptoAlgorithm::SphincsSha2128s => Some(&sphincs::SHA2_128S),
+        CryptoAlgorithm::SphincsSha2128f => Some(&sphincs::SHA2_128F),
+        CryptoAlgorithm::SphincsShake128s => Some(&sphincs::SHAKE_128S),
+        CryptoAlgorithm::SphincsShake128f => Some(&sphincs::SHAKE_128F),
+        _ => None,
+    }
+}
+
+/// Checks that `message` carries a valid signature by the vault's key.
+pub fn authorize(
+    vault: &Account<QuantumVault>,
+    message: &[u8],
+    signature_buffer: &Account<SignatureBuffer>,
+    session: Option<&Account<VerificationSession>>,
+) -> Result<()> {
+    authorize_key(
+        vault.key(),
+        vault.algorithm,
+        &vault.public_key,
+        message,
+        signature_buffer,
+        session,
+    )
+}
+
+/// Checks `message` against the vault's `SignaturePolicy`. The PQ leg is
+/// satisfied by `signature_buffer` and a completed session over it, the
+/// classical leg by an Ed25519 precompile instruction signed by the vault owner.
+/// Under `HybridEither`, supplying a session selects the PQ leg.
+pub fn authorize_with_policy(
+    vault: &Account<QuantumVault>,
+    message: &[u8],
+    signature_buffer: Option<&Account<SignatureBuffer>>,
+    session: Option<&Account<VerificationSession>>,
+    instructions: &AccountInfo,
+) -> Result<()> {
+    let classical = || authorize_classical(vault, message, instructions);
+    let post_quantum = || -> Result<()> {
+        let signature_buffer = signature_buffer.ok_or(QuantumCustodyError::PqSignatureRequired)?;
+        authorize(vault, message, signature_buffer, session)
+    };
+    
+    // A session is closed by whichever instruction it is passed to, so one the
+    // PQ leg does not consume is rejected rather than silently discarded
+    match vault.signature_policy {
+        SignaturePolicy::PqOnly => post_quantum(),
+        SignaturePolicy::ClassicalOnly => {
+            require!(session.is_none(), QuantumCustodyError::UnusedVerificationSession);
+            classical()
+        }
+        SignaturePolicy::HybridBoth => classical().and_then(|_| post_quantum()),
+        SignaturePolicy::HybridEither if session.is_some() => post_quantum(),
+        SignaturePolicy::HybridEither => classical(),
+    }
+}
+
+/// For operations that change or retire the vault key: the PQ signature is
+/// always required, so the owner's Ed25519 key alone can never take over the
+/// vault, and the classical leg is added when the policy demands it.
+pub fn authorize_with_pq_key(
+    vault: &Account<QuantumVault>,
+    message: &[u8],
+    signature_buffer: &Account<SignatureBuffer>,
+    session: Option<&Account<VerificationSession>>,
+    instructions: &AccountInfo,
+) -> Result<()> {
+    authorize(vault, message, signature_buffer, session)?;
+    
+    if matches!(
+        vault.signature_policy,
+        SignaturePolicy::ClassicalOnly | SignaturePolicy::HybridBoth
+    ) {
+        authorize_classical(vault, message, instructions)?;
+    }
+    
+    Ok(())
+}
+
+/// Checks that an Ed25519 precompile instruction in this transaction verifies
+/// the vault owner's signature over `message`.
+pub fn authorize_classical(
+    vault: &Account<QuantumVault>,
+    message: &[u8],
+    instructions: &AccountInfo,
+) -> Result<()> {
+    require!(
+        ed25519::is_signed(instructions, &vault.owner, message)?,
+        QuantumCustodyError::ClassicalSignatureRequired
+    );
+    Ok(())
+}
+
+/// Checks that `message` is signed by a session grant's delegated key: an
+/// Ed25519 key through the precompile, a PQ key from `signature_buffer` and a
+/// completed session over it.
+pub fn authorize_session(
+    grant: &Account<SessionGrant>,
+    message: &[u8],
+    signature_buffer: Option<&Account<SignatureBuffer>>,
+    session: Option<&Account<VerificationSession>>,
+    instructions: &AccountInfo,
+) -> Result<()> {
+    match &grant.session_key {
+        SessionKey::Ed25519(signer) => {
+            require!(session.is_none(), QuantumCustodyError::UnusedVerificationSession);
+            require!(
+                ed25519::is_signed(instructions, signer, message)?,
+                QuantumCustodyError::ClassicalSignatureRequired
+            );
+            Ok(())
+        }
+        SessionKey::PostQuantum { algorithm, public_key } => {
+            let signature_buffer = signature_buffer.ok_or(QuantumCustodyError::PqSignatureRequired)?;
+            authorize_key(grant.key(), *algorithm, public_key, message, signature_buffer, session)
+        }
+    }
+}
+
+/// Checks that `message` carries a valid signature by `public_key`, acting for
+/// `subject`. The signature must already have been verified by a completed
+/// session for the same subject and buffer.
+pub fn authorize_key(
+    subject: Pubkey,
+    algorithm: CryptoAlgorithm,
+    public_key: &[u8],
+    message: &[u8],
+    signature_buffer: &Account<SignatureBuffer>,
+    session: Option<&Account<VerificationSession>>,
+) -> Result<()> {
+    let session = session.ok_or(QuantumCustodyError::VerificationSessionRequired)?;
+    
+    require!(
+        session.vault == subject
+            && session.signature_buffer == signature_buffer.key()
+            && session.algorithm == algorithm
+            && session.public_key[..] == public_key[..]
+            && session.message_hash == hash(message).to_bytes(),
+        QuantumCustodyError::VerificationSessionMismatch
+    );
+    
+    require!(session.verified, QuantumCustodyError::VerificationIncomplete);
+    
+    Ok(())
+}
+
+/// Verifies the vault's signature over `action` bound to `nonce` and
+/// `expiry_slot`, then consumes the nonce.
+pub fn authorize_action(
+    vault: &mut Account<QuantumVault>,
+    action: &VaultAction,
+    nonce: u64,
+    expiry_slot: u64,
+    signature_buffer: &Account<SignatureBuffer>,
+    session: Option<&Account<VerificationSession>>,
+) -> Result<()> {
+    let payload = action.try_to_vec()?;
+    let message = SigningDomain::new(vault.key(), nonce, expiry_slot, &payload).to_message()?;
+    
+    authorize(vault, &message, signature_buffer, session)?;
+    vault.consume_nonce(nonce, expiry_slot, &Clock::get()?)
+}
@@ -40,4 +40,169 @@ pub enum QuantumCustodyError {
     
     #[msg("Mathematical overflow occurred")]
     MathOverflow,
+    
+    #[msg("Buffer length exceeds the largest supported payload")]
+    InvalidBufferLength,
+    
+    #[msg("Chunk does not continue the buffer or exceeds its declared length")]
+    InvalidBufferWrite,
+    
+    #[msg("Buffer has already been finalized")]
+    BufferAlreadyFinalized,
+    
+    #[msg("Buffer must be finalized before it can be consumed")]
+    BufferNotFinalized,
+    
+    #[msg("SPHINCS+ signatures must be checked through a verification session")]
+    VerificationSessionRequired,
+    
+    #[msg("Verification session does not match this vault, signature or message")]
+    VerificationSessionMismatch,
+    
+    #[msg("Verification session has not completed")]
+    VerificationIncomplete,
+    
+    #[msg("Verification session has already completed")]
+    VerificationAlreadyComplete,
+    
+    #[msg("Nonce has already been used for this vault")]
+    NonceAlreadyUsed,
+    
+    #[msg("Nonce does not match the vault's next nonce")]
+    InvalidNonce,
+    
+    #[msg("Signed message has expired")]
+    SignatureExpired,
+    
+    #[msg("Vault balance is insufficient for this withdrawal")]
+    InsufficientVaultBalance,
+    
+    #[msg("Multisig threshold must be between 1 and the number of members")]
+    InvalidThreshold,
+    
+    #[msg("Multisig member list is empty, too long or contains duplicates")]
+    InvalidMembers,
+    
+    #[msg("Member has already approved this proposal")]
+    AlreadyApproved,
+    
+    #[msg("Proposal has not reached its approval threshold")]
+    ThresholdNotMet,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Accounts required by the proposal action were not provided")]
+    MissingActionAccounts,
+    
+    #[msg("Vault signature policy requires a post-quantum signature")]
+    PqSignatureRequired,
+    
+    #[msg("Vault signature policy requires an Ed25519 signature by the owner")]
+    ClassicalSignatureRequired,
+    
+    #[msg("Vault label exceeds 32 bytes")]
+    LabelTooLong,
+    
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    
+    #[msg("Security level must be 2, 3 or 5")]
+    InvalidSecurityLevel,
+    
+    #[msg("Rotation period must be 7 to 365 days with a shorter grace period")]
+    InvalidRotationPolicy,
+    
+    #[msg("Vault still holds SOL or tokens")]
+    VaultNotEmpty,
+    
+    #[msg("Recovery delay must be between 1 and 30 days")]
+    InvalidRecoveryDelay,
+    
+    #[msg("Guardian set changed since this recovery was proposed")]
+    RecoveryStale,
+    
+    #[msg("Recovery timelock has not expired")]
+    RecoveryLocked,
+    
+    #[msg("Recovery proposal has expired")]
+    RecoveryExpired,
+    
+    #[msg("Recovery proposal is still live")]
+    RecoveryActive,
+    
+    #[msg("Withdrawal tiers must be sorted, unique per asset and delay at most 30 days")]
+    InvalidWithdrawalTiers,
+    
+    #[msg("Withdrawal amount is time-locked and must be queued")]
+    WithdrawalQueueRequired,
+    
+    #[msg("Queued withdrawal has not unlocked yet")]
+    WithdrawalLocked,
+    
+    #[msg("Spending limits must be unique per asset and period, with periods of 1 hour to 30 days")]
+    InvalidSpendingLimits,
+    
+    #[msg("Withdrawal exceeds the vault's spending limit; queue it instead")]
+    SpendingLimitExceeded,
+    
+    #[msg("Destination is not in the vault's address book")]
+    DestinationNotAllowed,
+    
+    #[msg("Address book entry is still in its cooling-off period")]
+    DestinationCoolingOff,
+    
+    #[msg("Withdrawal exceeds the address book entry's cap")]
+    DestinationCapExceeded,
+    
+    #[msg("Address book is full")]
+    AddressBookFull,
+    
+    #[msg("Vault is frozen")]
+    VaultFrozen,
+    
+    #[msg("Vault is not frozen")]
+    VaultNotFrozen,
+    
+    #[msg("Unfreeze delay has not passed")]
+    UnfreezeLocked,
+    
+    #[msg("Session scope must list 1 to 4 programs and expire within a day")]
+    InvalidSessionGrant,
+    
+    #[msg("Session grant has expired")]
+    SessionExpired,
+    
+    #[msg("Operation is outside the session grant's scope")]
+    SessionScopeExceeded,
+    
+    #[msg("Inactivity period must be 30 days to 5 years and claim delay 1 to 90 days")]
+    InvalidInheritanceConfig,
+    
+    #[msg("Vault has been active within its inactivity period")]
+    VaultStillActive,
+    
+    #[msg("Vault showed activity after the claim started")]
+    ClaimObjected,
+    
+    #[msg("Inheritance claim delay has not passed")]
+    ClaimLocked,
+    
+    #[msg("Verification session is not used by this operation")]
+    UnusedVerificationSession,
+    
+    #[msg("Token account was not opened by the vault")]
+    TokenAccountNotOpened,
+    
+    #[msg("Vault has opened the maximum number of token accounts")]
+    TokenAccountLimitReached,
+    
+    #[msg("An account belonging to the vault was not closed with it")]
+    VaultAccountNotClosed,
+    
+    #[msg("Authorized under a previous owner of the vault")]
+    OwnershipChanged,
+    
+    #[msg("Proposal has not expired")]
+    ProposalActive,
 }
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<crate::AcceptAuthority>) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let previous_authority = global_state.authority;
+    
+    global_state.authority = ctx.accounts.new_authority.key();
+    global_state.pending_authority = None;
+    
+    emit!(AuthorityTransferredEvent {
+        previous_authority,
+        authority: global_state.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<crate::AcceptOwner>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let previous_owner = vault.owner;
+    
+    vault.transfer_ownership(ctx.accounts.new_owner.key())?;
+    
+    emit!(OwnerTransferredEvent {
+        vault: vault.key(),
+        previous_owner,
+        owner: vault.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct OwnerTransferredEvent {
+    pub vault: Pubkey,
+    pub previous_owner: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+/// Adds or replaces the entry for `destination` and `mint`. Replacing an
+/// entry restarts its cooling-off period, so caps cannot be raised instantly.
+pub fn handler(
+    ctx: Context<crate::AddAddressBookEntry>,
+    destination: Pubkey,
+    mint: Option<Pubkey>,
+    cap: Option<u64>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    let change = AddressBookChange::Add { destination, mint, cap };
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &change.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.has_address_book = true;
+    
+    let address_book = &mut ctx.accounts.address_book;
+    address_book.vault = vault.key();
+    address_book.bump = ctx.bumps.address_book;
+    
+    let entry = AddressBookEntry {
+        destination,
+        mint,
+        cap,
+        active_at: clock.unix_timestamp.saturating_add(AddressBook::COOLING_OFF),
+    };
+    match address_book.find(mint, destination) {
+        Some(index) => address_book.entries[index] = entry,
+        None => {
+            require!(
+                address_book.entries.len() < AddressBook::MAX_ENTRIES,
+                QuantumCustodyError::AddressBookFull
+            );
+            address_book.entries.push(entry);
+        }
+    }
+    
+    emit!(AddressBookEntryAddedEvent {
+        vault: vault.key(),
+        destination,
+        mint,
+        cap,
+        active_at: entry.active_at,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AddressBookEntryAddedEvent {
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub cap: Option<u64>,
+    pub active_at: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(ctx: Context<crate::ApproveProposal>, member_index: u8) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    let clock = Clock::get()?;
+    
+    require!(!proposal.executed, QuantumCustodyError::ProposalAlreadyExecuted);
+    require!(clock.slot <= proposal.expiry_slot, QuantumCustodyError::SignatureExpired);
+    
+    let member = *multisig.members
+        .get(member_index as usize)
+        .ok_or(QuantumCustodyError::InvalidMembers)?;
+    ctx.accounts.global_state.check_algorithm(member.algorithm)?;
+    
+    require!(
+        proposal.approvals & (1 << member_index) == 0,
+        QuantumCustodyError::AlreadyApproved
+    );
+    
+    {
+        let public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+        require!(
+            hash(&public_key).to_bytes() == member.key_hash,
+            QuantumCustodyError::Unauthorized
+        );
+        
+        crypto::authorize_key(
+            multisig.key(),
+            member.algorithm,
+            &public_key,
+            &proposal.signing_message()?,
+            &ctx.accounts.signature_buffer,
+            ctx.accounts.verification_session.as_ref(),
+        )?;
+    }
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approvals |= 1 << member_index;
+    proposal.approval_count += 1;
+    
+    emit!(ProposalApprovedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        member_index,
+        approval_count: proposal.approval_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub member_index: u8,
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::ApproveRecovery>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery = &mut ctx.accounts.recovery;
+    let clock = Clock::get()?;
+    
+    require!(
+        recovery.guardians_hash == vault.recovery.guardians_hash(),
+        QuantumCustodyError::RecoveryStale
+    );
+    
+    let guardian_index = vault.recovery
+        .guardian_index(&ctx.accounts.guardian.key())
+        .ok_or(QuantumCustodyError::Unauthorized)?;
+    recovery.approve(&vault.recovery, guardian_index, clock.unix_timestamp)?;
+    
+    emit!(RecoveryApprovedEvent {
+        vault: vault.key(),
+        guardian: ctx.accounts.guardian.key(),
+        approval_count: recovery.approval_count,
+        unlock_at: recovery.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RecoveryApprovedEvent {
+    pub vault: Pubkey,
+    pub guardian: Pubkey,
+    pub approval_count: u8,
+    pub unlock_at: Option<i64>,
+    pub timestamp: i64,
+}
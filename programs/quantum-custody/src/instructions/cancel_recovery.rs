@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+pub fn handler(ctx: Context<crate::CancelRecovery>, nonce: u64, expiry_slot: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery = &ctx.accounts.recovery;
+    let clock = Clock::get()?;
+    
+    // Holding the current key is proof it is not lost, so it alone can cancel
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &recovery.cancel_statement()).to_message()?;
+    crypto::authorize(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    emit!(RecoveryCancelledEvent {
+        vault: vault.key(),
+        proposer: recovery.proposer,
+        new_key_fingerprint: recovery.new_key_hash,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RecoveryCancelledEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_key_fingerprint: [u8; 32],
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+pub fn handler(ctx: Context<crate::CancelWithdrawal>, nonce: u64, expiry_slot: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let pending = &ctx.accounts.pending_withdrawal;
+    let clock = Clock::get()?;
+    
+    // PQ-only so a compromised owner wallet cannot block a cancellation
+    let signed_message = SigningDomain::new(
+        vault.key(),
+        nonce,
+        expiry_slot,
+        &PendingWithdrawal::cancel_statement(&pending.key()),
+    )
+    .to_message()?;
+    crypto::authorize(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    emit!(WithdrawalCancelledEvent {
+        vault: vault.key(),
+        pending_withdrawal: pending.key(),
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalCancelledEvent {
+    pub vault: Pubkey,
+    pub pending_withdrawal: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
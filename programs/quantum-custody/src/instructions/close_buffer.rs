@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Closes a signature buffer that will not be consumed, such as one left
+/// half-written or holding a rejected signature, freeing its address and
+/// returning its rent to the authority.
+pub fn handler(ctx: Context<crate::CloseBuffer>) -> Result<()> {
+    emit!(BufferClosedEvent {
+        buffer: ctx.accounts.buffer.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BufferClosedEvent {
+    pub buffer: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
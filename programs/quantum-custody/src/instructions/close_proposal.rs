@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Clears a multisig proposal whose `expiry_slot` has passed without it being
+/// executed, returning its rent to the proposer. Callable by anyone.
+pub fn handler(ctx: Context<crate::CloseProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let clock = Clock::get()?;
+    
+    require!(clock.slot > proposal.expiry_slot, QuantumCustodyError::ProposalActive);
+    
+    emit!(ProposalClosedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProposalClosedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Clears a recovery proposal that expired or was made for an earlier guardian
+/// set, returning its rent to the proposer. Callable by anyone.
+pub fn handler(ctx: Context<crate::CloseRecovery>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery = &ctx.accounts.recovery;
+    let clock = Clock::get()?;
+    
+    require!(
+        clock.unix_timestamp >= recovery.expires_at
+            || recovery.guardians_hash != vault.recovery.guardians_hash(),
+        QuantumCustodyError::RecoveryActive
+    );
+    
+    emit!(RecoveryClosedEvent {
+        vault: vault.key(),
+        proposer: recovery.proposer,
+        new_key_fingerprint: recovery.new_key_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RecoveryClosedEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_key_fingerprint: [u8; 32],
+    pub timestamp: i64,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Closes a verification session, with its signature buffer, that will not be
+/// consumed: one whose signature failed verification, or one simply
+/// abandoned. Rent returns to the payer.
+pub fn handler(ctx: Context<crate::CloseSession>) -> Result<()> {
+    let session = &ctx.accounts.session;
+    
+    emit!(VerificationSessionClosedEvent {
+        session: session.key(),
+        vault: session.vault,
+        signature_buffer: session.signature_buffer,
+        verified: session.verified,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VerificationSessionClosedEvent {
+    pub session: Pubkey,
+    pub vault: Pubkey,
+    pub signature_buffer: Pubkey,
+    pub verified: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, CloseAccount, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+/// Closes the vault, returning its rent to the owner. Its key history,
+/// encryption key and address book must be passed whenever they exist, and
+/// every token account it opened must be passed as a remaining account; all
+/// of them are closed with it, and the token accounts must be empty.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::CloseVault<'info>>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let vault_info = vault.to_account_info();
+    let clock = Clock::get()?;
+    
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    require!(
+        vault_info.lamports() <= rent_exempt_minimum,
+        QuantumCustodyError::VaultNotEmpty
+    );
+    require!(
+        (!vault.has_key_history || ctx.accounts.key_history.is_some())
+            && (!vault.has_encryption_key || ctx.accounts.encryption_key.is_some())
+            && (!vault.has_address_book || ctx.accounts.address_book.is_some()),
+        QuantumCustodyError::VaultAccountNotClosed
+    );
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, QuantumVault::CLOSE_STATEMENT).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let index = vault.vault_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+    
+    for token_account_info in ctx.remaining_accounts {
+        // Already closed through `execute`
+        if token_account_info.data_is_empty() {
+            continue;
+        }
+        require_keys_eq!(*token_account_info.owner, token::ID, QuantumCustodyError::Unauthorized);
+        let token_account = TokenAccount::try_deserialize(&mut &token_account_info.data.borrow()[..])?;
+        require_keys_eq!(token_account.owner, vault.key(), QuantumCustodyError::Unauthorized);
+        require!(token_account.amount == 0, QuantumCustodyError::VaultNotEmpty);
+        
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: token_account_info.clone(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: vault_info.clone(),
+            },
+            &[seeds],
+        ))?;
+    }
+    
+    for mint in &vault.token_mints {
+        let token_account = get_associated_token_address(&vault.key(), mint);
+        require!(
+            ctx.remaining_accounts.iter().any(|account| account.key() == token_account),
+            QuantumCustodyError::VaultAccountNotClosed
+        );
+    }
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    ctx.accounts.global_state.record_vault_closed()?;
+    
+    emit!(VaultClosedEvent {
+        vault: vault.key(),
+        owner: vault.owner,
+        vault_index: vault.vault_index,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VaultClosedEvent {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub vault_index: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+
+/// Hands the vault to its beneficiary: ownership moves to their wallet and
+/// the vault key to their PQ key. Guardians and the beneficiary setting are
+/// cleared, as they belonged to the previous owner.
+pub fn handler(ctx: Context<crate::CompleteInheritanceClaim>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let claim = &ctx.accounts.claim;
+    let clock = Clock::get()?;
+    
+    let config = vault.inheritance.ok_or(QuantumCustodyError::Unauthorized)?;
+    require_keys_eq!(claim.beneficiary, config.beneficiary, QuantumCustodyError::Unauthorized);
+    require!(clock.unix_timestamp >= claim.unlock_at, QuantumCustodyError::ClaimLocked);
+    require!(vault.activity_count == claim.activity_count, QuantumCustodyError::ClaimObjected);
+    
+    ctx.accounts.global_state.check_algorithm(config.algorithm)?;
+    
+    let public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    require!(
+        hash(&public_key).to_bytes() == config.key_hash,
+        QuantumCustodyError::Unauthorized
+    );
+    require!(
+        public_key.len() == config.algorithm.public_key_size(),
+        QuantumCustodyError::InvalidPublicKeySize
+    );
+    
+    let key_history = &mut ctx.accounts.key_history;
+    key_history.vault = ctx.accounts.vault.key();
+    key_history.bump = ctx.bumps.key_history;
+    key_history.record(&ctx.accounts.vault, KeyRetirementReason::Inheritance, clock.unix_timestamp);
+    
+    let public_key = public_key.to_vec();
+    let previous_owner = vault.owner;
+    let vault = &mut ctx.accounts.vault;
+    vault.transfer_ownership(config.beneficiary)?;
+    vault.algorithm = config.algorithm;
+    vault.public_key = public_key;
+    vault.last_key_rotation = clock.unix_timestamp;
+    vault.has_key_history = true;
+    vault.record_activity(clock.unix_timestamp);
+    vault.recovery = RecoveryConfig::default();
+    vault.inheritance = None;
+    vault.compliance_status.record_audit(clock.unix_timestamp);
+    
+    emit!(InheritanceClaimCompletedEvent {
+        vault: vault.key(),
+        previous_owner,
+        beneficiary: config.beneficiary,
+        key_fingerprint: config.key_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct InheritanceClaimCompletedEvent {
+    pub vault: Pubkey,
+    pub previous_owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub key_fingerprint: [u8; 32],
+    pub timestamp: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto::{self, dilithium, sphincs};
+
+pub fn handler(ctx: Context<crate::ContinueVerification>, steps: u16) -> Result<()> {
+    let session: &mut VerificationSession = &mut ctx.accounts.session;
+    let signature = SignatureBuffer::payload(&ctx.accounts.signature_buffer)?;
+    
+    require!(!session.verified, QuantumCustodyError::VerificationAlreadyComplete);
+    
+    let end = session.next_step
+        .saturating_add(steps as u32)
+        .min(session.total_steps);
+    let batch = session.next_step..end;
+    session.next_step = end;
+    
+    let complete = session.next_step == session.total_steps;
+    let valid = match &mut session.progress {
+        VerificationProgress::Dilithium(progress) => {
+            let params = crypto::dilithium_params(session.algorithm)
+                .ok_or(QuantumCustodyError::UnsupportedOperation)?;
+            for step in batch {
+                dilithium::step(params, &session.public_key, &signature, step, progress);
+            }
+            !complete || dilithium::finish(params, &signature, progress)
+        }
+        VerificationProgress::Sphincs(progress) => {
+            let params = crypto::sphincs_params(session.algorithm)
+                .ok_or(QuantumCustodyError::UnsupportedOperation)?;
+            for step in batch {
+                sphincs::step(params, &session.public_key, &signature, step, progress);
+            }
+            !complete || progress.node[..] == session.public_key[sphincs::N..]
+        }
+    };
+    
+    require!(valid, QuantumCustodyError::SignatureVerificationFailed);
+    
+    if !complete {
+        return Ok(());
+    }
+    
+    session.verified = true;
+    let vault = session.vault;
+    
+    emit!(VerificationCompletedEvent {
+        session: ctx.accounts.session.key(),
+        vault,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VerificationCompletedEvent {
+    pub session: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
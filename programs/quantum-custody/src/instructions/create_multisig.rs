@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(
+    ctx: Context<crate::CreateMultisig>,
+    multisig_id: u64,
+    threshold: u8,
+    members: Vec<MultisigMember>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    
+    require!(
+        !members.is_empty() && members.len() <= MultisigVault::MAX_MEMBERS,
+        QuantumCustodyError::InvalidMembers
+    );
+    
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            !member.algorithm.is_kem(),
+            QuantumCustodyError::InvalidAlgorithm
+        );
+        require!(member.algorithm.is_verifiable(), QuantumCustodyError::UnsupportedOperation);
+        ctx.accounts.global_state.check_algorithm(member.algorithm)?;
+        require!(
+            members[..i].iter().all(|other| other.key_hash != member.key_hash),
+            QuantumCustodyError::InvalidMembers
+        );
+    }
+    
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        QuantumCustodyError::InvalidThreshold
+    );
+    
+    multisig.creator = ctx.accounts.creator.key();
+    multisig.multisig_id = multisig_id;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.bump = ctx.bumps.multisig;
+    
+    ctx.accounts.global_state.record_vault()?;
+    
+    emit!(MultisigCreatedEvent {
+        multisig: multisig.key(),
+        creator: multisig.creator,
+        member_count: multisig.members.len() as u8,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct MultisigCreatedEvent {
+    pub multisig: Pubkey,
+    pub creator: Pubkey,
+    pub member_count: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
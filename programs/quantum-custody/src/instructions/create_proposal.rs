@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(
+    ctx: Context<crate::CreateProposal>,
+    action: VaultAction,
+    expiry_slot: u64,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+    
+    require!(expiry_slot >= clock.slot, QuantumCustodyError::SignatureExpired);
+    
+    proposal.multisig = multisig.key();
+    proposal.index = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.expiry_slot = expiry_slot;
+    proposal.approvals = 0;
+    proposal.approval_count = 0;
+    proposal.executed = false;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+    
+    multisig.proposal_count = multisig.proposal_count
+        .checked_add(1)
+        .ok_or(QuantumCustodyError::MathOverflow)?;
+    
+    emit!(ProposalCreatedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer: proposal.proposer,
+        expiry_slot,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub expiry_slot: u64,
+    pub timestamp: i64,
+}
@@ -6,57 +6,81 @@ pub fn handler(
     ctx: Context<crate::CreateVault>,
     algorithm: u8,
     public_key: Vec<u8>,
+    signature_policy: SignaturePolicy,
+    label: String,
+    rotation_policy: Option<RotationPolicy>,
 ) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
     let vault = &mut ctx.accounts.vault;
     let clock = Clock::get()?;
     
-    let crypto_algorithm = match algorithm {
-        0 => CryptoAlgorithm::Dilithium2,
-        1 => CryptoAlgorithm::Dilithium3,
-        2 => CryptoAlgorithm::Dilithium5,
-        3 => CryptoAlgorithm::SphincsSha2128s,
-        4 => CryptoAlgorithm::SphincsSha2128f,
-        5 => CryptoAlgorithm::SphincsShake128s,
-        6 => CryptoAlgorithm::SphincsShake128f,
-        7 => CryptoAlgorithm::Kyber512,
-        8 => CryptoAlgorithm::Kyber768,
-        9 => CryptoAlgorithm::Kyber1024,
-        _ => return Err(QuantumCustodyError::InvalidAlgorithm.into()),
-    };
+    let crypto_algorithm = CryptoAlgorithm::from_u8(algorithm)?;
+    require!(!crypto_algorithm.is_kem(), QuantumCustodyError::InvalidAlgorithm);
+    require!(crypto_algorithm.is_verifiable(), QuantumCustodyError::UnsupportedOperation);
+    ctx.accounts.global_state.check_algorithm(crypto_algorithm)?;
     
     require!(
         public_key.len() == crypto_algorithm.public_key_size(),
         QuantumCustodyError::InvalidPublicKeySize
     );
     
+    require!(
+        label.len() <= QuantumVault::MAX_LABEL_LEN,
+        QuantumCustodyError::LabelTooLong
+    );
+    
+    let rotation_policy = rotation_policy.unwrap_or(RotationPolicy::DEFAULT);
+    rotation_policy.validate()?;
+    
+    let vault_index = registry.vault_count;
+    registry.owner = ctx.accounts.owner.key();
+    registry.vault_count = vault_index
+        .checked_add(1)
+        .ok_or(QuantumCustodyError::MathOverflow)?;
+    registry.bump = ctx.bumps.registry;
+    
     vault.owner = ctx.accounts.owner.key();
+    vault.creator = ctx.accounts.owner.key();
+    vault.pending_owner = None;
+    vault.owner_epoch = 0;
+    vault.recovery = RecoveryConfig::default();
+    vault.withdrawal_tiers = Vec::new();
+    vault.pending_withdrawal_tiers = None;
+    vault.spending_limits = Vec::new();
+    vault.pending_spending_limits = None;
+    vault.has_address_book = false;
+    vault.has_key_history = false;
+    vault.has_encryption_key = false;
+    vault.token_mints = Vec::new();
+    vault.frozen = false;
+    vault.unfreeze_at = None;
+    vault.last_activity = clock.unix_timestamp;
+    vault.activity_count = 0;
+    vault.inheritance = None;
     vault.algorithm = crypto_algorithm;
     vault.public_key = public_key.clone();
     vault.created_at = clock.unix_timestamp;
     vault.last_key_rotation = clock.unix_timestamp;
     vault.transaction_count = 0;
+    vault.nonce = 0;
+    vault.signature_policy = signature_policy;
+    vault.vault_index = vault_index;
+    vault.label = label;
+    vault.rotation_policy = rotation_policy;
     vault.compliance_status = ComplianceStatus::default();
     vault.bump = ctx.bumps.vault;
     
-    match crypto_algorithm {
-        CryptoAlgorithm::Kyber512 | CryptoAlgorithm::Kyber768 | CryptoAlgorithm::Kyber1024 => {
-            vault.compliance_status.nist_fips_203_compliant = true;
-        }
-        CryptoAlgorithm::Dilithium2 | CryptoAlgorithm::Dilithium3 | CryptoAlgorithm::Dilithium5 => {
-            vault.compliance_status.nist_fips_204_compliant = true;
-        }
-        _ => {
-            vault.compliance_status.nist_fips_205_compliant = true;
-        }
-    }
-    
+    vault.compliance_status.record_audit(clock.unix_timestamp);
     vault.compliance_status.quantum_readiness_score = 75;
-    vault.compliance_status.last_audit = clock.unix_timestamp;
+    
+    ctx.accounts.global_state.record_vault()?;
     
     emit!(VaultCreatedEvent {
         vault: vault.key(),
         owner: vault.owner,
+        vault_index,
         algorithm: crypto_algorithm,
+        signature_policy,
         timestamp: clock.unix_timestamp,
     });
     
@@ -67,6 +91,8 @@ pub fn handler(
 pub struct VaultCreatedEvent {
     pub vault: Pubkey,
     pub owner: Pubkey,
+    pub vault_index: u64,
     pub algorithm: CryptoAlgorithm,
+    pub signature_policy: SignaturePolicy,
     pub timestamp: i64,
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+pub fn handler(ctx: Context<crate::DepositSol>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    emit!(SolDepositedEvent {
+        vault: ctx.accounts.vault.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SolDepositedEvent {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::Execute<'info>>,
+    instructions: Vec<VaultInstruction>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let instructions_hash = hash(&instructions.try_to_vec()?).to_bytes();
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    
+    // Vaults with transfer controls must use the withdrawal instructions instead
+    require!(
+        !ctx.accounts.vault.has_transfer_controls(),
+        QuantumCustodyError::WithdrawalQueueRequired
+    );
+    
+    crypto::authorize_action(
+        &mut ctx.accounts.vault,
+        &VaultAction::Execute { instructions_hash },
+        nonce,
+        expiry_slot,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let vault = &ctx.accounts.vault;
+    let index = vault.vault_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+    
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(vault.to_account_info());
+    invoke_instructions(&instructions, &account_infos, seeds)?;
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(VaultExecutedEvent {
+        vault: vault.key(),
+        instructions_hash,
+        instruction_count: instructions.len() as u32,
+        nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Invokes `instructions` in order with the PDA derived from `seeds` as signer.
+pub fn invoke_instructions(
+    instructions: &[VaultInstruction],
+    account_infos: &[AccountInfo],
+    seeds: &[&[u8]],
+) -> Result<()> {
+    require!(
+        !instructions.is_empty(),
+        QuantumCustodyError::UnsupportedOperation
+    );
+    
+    // Re-entering the custody program could bypass its own authorization checks
+    require!(
+        instructions.iter().all(|ix| ix.program_id != crate::ID),
+        QuantumCustodyError::UnsupportedOperation
+    );
+    
+    for instruction in instructions {
+        invoke_signed(&Instruction::from(instruction), account_infos, &[seeds])?;
+    }
+    
+    Ok(())
+}
+
+#[event]
+pub struct VaultExecutedEvent {
+    pub vault: Pubkey,
+    pub instructions_hash: [u8; 32],
+    pub instruction_count: u32,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
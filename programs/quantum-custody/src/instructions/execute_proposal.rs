@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token::{self, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::instructions::execute::invoke_instructions;
+use crate::instructions::withdraw_sol::transfer_lamports;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::ExecuteProposal<'info>>,
+    instructions: Vec<VaultInstruction>,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    
+    require!(!proposal.executed, QuantumCustodyError::ProposalAlreadyExecuted);
+    require!(Clock::get()?.slot <= proposal.expiry_slot, QuantumCustodyError::SignatureExpired);
+    require!(
+        proposal.approval_count >= multisig.threshold,
+        QuantumCustodyError::ThresholdNotMet
+    );
+    
+    let multisig_id = multisig.multisig_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"multisig", multisig.creator.as_ref(), &multisig_id, &[multisig.bump]];
+    
+    match proposal.action.clone() {
+        VaultAction::WithdrawSol { destination, amount } => {
+            let destination_info = ctx.accounts.destination
+                .as_ref()
+                .ok_or(QuantumCustodyError::MissingActionAccounts)?;
+            require_keys_eq!(destination_info.key(), destination, QuantumCustodyError::Unauthorized);
+            
+            transfer_lamports(&multisig.to_account_info(), &destination_info.to_account_info(), amount)?;
+        }
+        VaultAction::WithdrawSpl { mint, destination, amount } => {
+            let (Some(source), Some(destination_account), Some(token_program)) = (
+                ctx.accounts.multisig_token_account.as_ref(),
+                ctx.accounts.destination_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(QuantumCustodyError::MissingActionAccounts.into());
+            };
+            
+            require_keys_eq!(source.owner, multisig.key(), QuantumCustodyError::Unauthorized);
+            require_keys_eq!(source.mint, mint, QuantumCustodyError::Unauthorized);
+            require_keys_eq!(destination_account.key(), destination, QuantumCustodyError::Unauthorized);
+            
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: source.to_account_info(),
+                        to: destination_account.to_account_info(),
+                        authority: multisig.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            )?;
+        }
+        VaultAction::Execute { instructions_hash } => {
+            require!(
+                hash(&instructions.try_to_vec()?).to_bytes() == instructions_hash,
+                QuantumCustodyError::Unauthorized
+            );
+            
+            let mut account_infos = ctx.remaining_accounts.to_vec();
+            account_infos.push(multisig.to_account_info());
+            invoke_instructions(&instructions, &account_infos, seeds)?;
+        }
+    }
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(ProposalExecutedEvent {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        index: proposal.index,
+        approval_count: proposal.approval_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::ExecuteRecovery>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery = &ctx.accounts.recovery;
+    let clock = Clock::get()?;
+    
+    require!(
+        recovery.guardians_hash == vault.recovery.guardians_hash(),
+        QuantumCustodyError::RecoveryStale
+    );
+    
+    let unlock_at = recovery.unlock_at.ok_or(QuantumCustodyError::ThresholdNotMet)?;
+    require!(clock.unix_timestamp >= unlock_at, QuantumCustodyError::RecoveryLocked);
+    require!(clock.unix_timestamp < recovery.expires_at, QuantumCustodyError::RecoveryExpired);
+    
+    let new_public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    require!(
+        hash(&new_public_key).to_bytes() == recovery.new_key_hash,
+        QuantumCustodyError::Unauthorized
+    );
+    require!(
+        new_public_key.len() == vault.algorithm.public_key_size(),
+        QuantumCustodyError::InvalidPublicKeySize
+    );
+    
+    let key_history = &mut ctx.accounts.key_history;
+    key_history.vault = ctx.accounts.vault.key();
+    key_history.bump = ctx.bumps.key_history;
+    key_history.record(&ctx.accounts.vault, KeyRetirementReason::Recovery, clock.unix_timestamp);
+    
+    let old_key_fingerprint = hash(&vault.public_key).to_bytes();
+    let new_public_key = new_public_key.to_vec();
+    let vault = &mut ctx.accounts.vault;
+    vault.public_key = new_public_key;
+    vault.last_key_rotation = clock.unix_timestamp;
+    vault.has_key_history = true;
+    vault.record_activity(clock.unix_timestamp);
+    
+    emit!(KeyRecoveredEvent {
+        vault: vault.key(),
+        proposer: recovery.proposer,
+        old_key_fingerprint,
+        new_key_fingerprint: recovery.new_key_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct KeyRecoveredEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub old_key_fingerprint: [u8; 32],
+    pub new_key_fingerprint: [u8; 32],
+    pub timestamp: i64,
+}
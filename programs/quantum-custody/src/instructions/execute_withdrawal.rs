@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::instructions::withdraw_sol::transfer_lamports;
+
+/// Releases a queued withdrawal once unlocked. Callable by anyone, since the
+/// destination and amount were fixed when the vault key queued it.
+pub fn handler(ctx: Context<crate::ExecuteWithdrawal>) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+    let clock = Clock::get()?;
+    
+    require!(clock.unix_timestamp >= pending.unlock_at, QuantumCustodyError::WithdrawalLocked);
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    
+    let (mint, destination, amount) = match pending.action {
+        VaultAction::WithdrawSol { destination, amount } => (None, destination, amount),
+        VaultAction::WithdrawSpl { mint, destination, amount } => (Some(mint), destination, amount),
+        VaultAction::Execute { .. } => return err!(QuantumCustodyError::UnsupportedOperation),
+    };
+    
+    // Re-checked in case the entry was removed while the withdrawal was queued
+    ctx.accounts.vault.check_destination(
+        ctx.accounts.address_book.as_ref(),
+        mint,
+        destination,
+        amount,
+        clock.unix_timestamp,
+    )?;
+    // Limits were not checked against other withdrawals queued alongside this
+    // one, so only one held for the over-limit delay may exceed them
+    ctx.accounts.vault.record_spend(mint, amount, clock.unix_timestamp, !pending.over_limit)?;
+    
+    let vault = &ctx.accounts.vault;
+    
+    let (destination, amount) = match pending.action {
+        VaultAction::WithdrawSol { destination, amount } => {
+            require_keys_eq!(
+                ctx.accounts.destination.key(),
+                destination,
+                QuantumCustodyError::Unauthorized
+            );
+            
+            transfer_lamports(
+                &vault.to_account_info(),
+                &ctx.accounts.destination.to_account_info(),
+                amount,
+            )?;
+            (destination, amount)
+        }
+        VaultAction::WithdrawSpl { mint, destination, amount } => {
+            let (Some(vault_token_account), Some(token_program)) =
+                (&ctx.accounts.vault_token_account, &ctx.accounts.token_program)
+            else {
+                return err!(QuantumCustodyError::UnsupportedOperation);
+            };
+            require_keys_eq!(vault_token_account.mint, mint, QuantumCustodyError::Unauthorized);
+            vault.check_token_account(&vault.key(), &mint, &vault_token_account.key())?;
+            require_keys_eq!(
+                ctx.accounts.destination.key(),
+                destination,
+                QuantumCustodyError::Unauthorized
+            );
+            
+            let index = vault.vault_index.to_le_bytes();
+            let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+            
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault_token_account.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            )?;
+            (destination, amount)
+        }
+        VaultAction::Execute { .. } => return err!(QuantumCustodyError::UnsupportedOperation),
+    };
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(WithdrawalExecutedEvent {
+        vault: vault.key(),
+        pending_withdrawal: pending.key(),
+        destination,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalExecutedEvent {
+    pub vault: Pubkey,
+    pub pending_withdrawal: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::Finalize>) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    
+    require!(!buffer.finalized, QuantumCustodyError::BufferAlreadyFinalized);
+    require!(
+        buffer.written == buffer.total_len,
+        QuantumCustodyError::InvalidBufferLength
+    );
+    
+    let digest = hash(&SignatureBuffer::payload(buffer)?).to_bytes();
+    buffer.finalized = true;
+    
+    emit!(BufferFinalizedEvent {
+        buffer: buffer.key(),
+        authority: buffer.authority,
+        len: buffer.total_len,
+        digest,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BufferFinalizedEvent {
+    pub buffer: Pubkey,
+    pub authority: Pubkey,
+    pub len: u32,
+    pub digest: [u8; 32],
+}
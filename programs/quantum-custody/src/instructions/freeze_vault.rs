@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Halts a vault suspected of compromise. Re-freezing a vault with a pending
+/// unfreeze request cancels that request.
+pub fn handler(ctx: Context<crate::FreezeVault>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        ctx.accounts.vault.can_freeze(&signer, &ctx.accounts.global_state),
+        QuantumCustodyError::Unauthorized
+    );
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.frozen = true;
+    vault.unfreeze_at = None;
+    
+    emit!(VaultFrozenEvent {
+        vault: vault.key(),
+        frozen_by: signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VaultFrozenEvent {
+    pub vault: Pubkey,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
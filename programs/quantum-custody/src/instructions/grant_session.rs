@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::GrantSession>,
+    session_key: SessionKey,
+    scope: SessionScope,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    session_key.validate()?;
+    scope.validate(clock.slot)?;
+    
+    let statement = SessionGrantStatement { session_key, scope };
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &statement.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    let grant = &mut ctx.accounts.session_grant;
+    grant.vault = vault.key();
+    grant.session_key = statement.session_key;
+    grant.scope = statement.scope;
+    grant.spent = 0;
+    grant.owner_epoch = vault.owner_epoch;
+    grant.created_at = clock.unix_timestamp;
+    grant.bump = ctx.bumps.session_grant;
+    
+    emit!(SessionGrantedEvent {
+        vault: vault.key(),
+        session_grant: grant.key(),
+        allowed_programs: grant.scope.allowed_programs.clone(),
+        max_amount: grant.scope.max_amount,
+        expiry_slot: grant.scope.expiry_slot,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionGrantedEvent {
+    pub vault: Pubkey,
+    pub session_grant: Pubkey,
+    pub allowed_programs: Vec<Pubkey>,
+    pub max_amount: u64,
+    pub expiry_slot: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
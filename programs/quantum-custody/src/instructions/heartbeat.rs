@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Proves the owner is alive without a PQ signature. Any pending inheritance
+/// claim started before this point can no longer complete.
+pub fn handler(ctx: Context<crate::Heartbeat>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    vault.record_activity(clock.unix_timestamp);
+    
+    emit!(HeartbeatEvent {
+        vault: vault.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct HeartbeatEvent {
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::InitBuffer>, buffer_id: u32, total_len: u32) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    
+    require!(
+        total_len > 0 && total_len as usize <= SignatureBuffer::MAX_LEN,
+        QuantumCustodyError::InvalidBufferLength
+    );
+    
+    buffer.authority = ctx.accounts.authority.key();
+    buffer.total_len = total_len;
+    buffer.written = 0;
+    buffer.finalized = false;
+    buffer.bump = ctx.bumps.buffer;
+    
+    emit!(BufferInitializedEvent {
+        buffer: buffer.key(),
+        authority: buffer.authority,
+        buffer_id,
+        total_len,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BufferInitializedEvent {
+    pub buffer: Pubkey,
+    pub authority: Pubkey,
+    pub buffer_id: u32,
+    pub total_len: u32,
+}
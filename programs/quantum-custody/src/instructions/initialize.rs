@@ -8,6 +8,10 @@ pub fn handler(ctx: Context<crate::Initialize>, authority: Pubkey) -> Result<()>
     global_state.total_vaults = 0;
     global_state.total_transactions = 0;
     global_state.bump = ctx.bumps.global_state;
+    global_state.pending_authority = None;
+    global_state.paused = false;
+    global_state.min_security_level = 0;
+    global_state.allowed_algorithms = GlobalState::ALL_ALGORITHMS;
     
     emit!(ProgramInitializedEvent {
         authority,
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::MigrateAlgorithm>,
+    algorithm: u8,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let new_algorithm = CryptoAlgorithm::from_u8(algorithm)?;
+    let new_public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    let clock = Clock::get()?;
+    
+    require!(
+        new_algorithm != vault.algorithm && !new_algorithm.is_kem(),
+        QuantumCustodyError::InvalidAlgorithm
+    );
+    require!(new_algorithm.is_verifiable(), QuantumCustodyError::UnsupportedOperation);
+    ctx.accounts.global_state.check_algorithm(new_algorithm)?;
+    
+    require!(
+        new_public_key.len() == new_algorithm.public_key_size(),
+        QuantumCustodyError::InvalidPublicKeySize
+    );
+    
+    require!(
+        ctx.accounts.signature_buffer.written as usize == vault.algorithm.signature_size(),
+        QuantumCustodyError::InvalidSignatureSize
+    );
+    
+    let old_algorithm = vault.algorithm;
+    let statement = MigrationStatement::new(new_algorithm, &vault.public_key, &new_public_key);
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &statement.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let key_history = &mut ctx.accounts.key_history;
+    key_history.vault = ctx.accounts.vault.key();
+    key_history.bump = ctx.bumps.key_history;
+    key_history.record(&ctx.accounts.vault, KeyRetirementReason::Migration, clock.unix_timestamp);
+    
+    let new_public_key = new_public_key.to_vec();
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.algorithm = new_algorithm;
+    vault.public_key = new_public_key;
+    vault.last_key_rotation = clock.unix_timestamp;
+    vault.has_key_history = true;
+    vault.compliance_status.record_audit(clock.unix_timestamp);
+    
+    emit!(AlgorithmMigratedEvent {
+        vault: vault.key(),
+        old_algorithm,
+        new_algorithm,
+        old_key_fingerprint: statement.old_key_hash,
+        new_key_fingerprint: statement.new_key_hash,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AlgorithmMigratedEvent {
+    pub vault: Pubkey,
+    pub old_algorithm: CryptoAlgorithm,
+    pub new_algorithm: CryptoAlgorithm,
+    pub old_key_fingerprint: [u8; 32],
+    pub new_key_fingerprint: [u8; 32],
+    pub nonce: u64,
+    pub timestamp: i64,
+}
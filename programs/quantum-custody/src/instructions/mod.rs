@@ -2,8 +2,58 @@ pub mod initialize;
 pub mod create_vault;
 pub mod sign_transaction;
 pub mod rotate_keys;
+pub mod init_buffer;
+pub mod write_chunk;
+pub mod finalize;
+pub mod close_buffer;
+pub mod close_session;
+pub mod start_verification;
+pub mod continue_verification;
+pub mod start_key_verification;
+pub mod deposit_sol;
+pub mod withdraw_sol;
+pub mod withdraw_spl;
+pub mod open_token_account;
+pub mod execute;
+pub mod create_multisig;
+pub mod create_proposal;
+pub mod start_member_verification;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod close_proposal;
+pub mod migrate_algorithm;
+pub mod publish_encryption_key;
+pub mod set_authority;
+pub mod accept_authority;
+pub mod pause_protocol;
+pub mod set_min_security_level;
+pub mod set_allowed_algorithms;
+pub mod set_rotation_policy;
+pub mod close_vault;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod set_guardians;
+pub mod propose_recovery;
+pub mod approve_recovery;
+pub mod cancel_recovery;
+pub mod execute_recovery;
+pub mod close_recovery;
+pub mod set_withdrawal_tiers;
+pub mod queue_withdrawal;
+pub mod execute_withdrawal;
+pub mod cancel_withdrawal;
+pub mod set_spending_limits;
+pub mod add_address_book_entry;
+pub mod remove_address_book_entry;
+pub mod freeze_vault;
+pub mod request_unfreeze;
+pub mod unfreeze_vault;
+pub mod grant_session;
+pub mod revoke_session;
+pub mod session_withdraw;
+pub mod session_execute;
+pub mod set_beneficiary;
+pub mod heartbeat;
+pub mod start_inheritance_claim;
+pub mod complete_inheritance_claim;
 
-pub use initialize::*;
-pub use create_vault::*;
-pub use sign_transaction::*;
-pub use rotate_keys::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Opens the vault's associated token account for a mint, or adopts one that
+/// was already created by a depositor, so withdrawals and `close_vault` can
+/// account for it.
+pub fn handler(ctx: Context<crate::OpenTokenAccount>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let vault = &mut ctx.accounts.vault;
+    vault.open_token_account(mint)?;
+    
+    emit!(TokenAccountOpenedEvent {
+        vault: vault.key(),
+        mint,
+        token_account: ctx.accounts.vault_token_account.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TokenAccountOpenedEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
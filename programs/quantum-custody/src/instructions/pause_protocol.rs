@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<crate::PauseProtocol>, paused: bool) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    global_state.paused = paused;
+    
+    emit!(ProtocolPausedEvent {
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProtocolPausedEvent {
+    pub paused: bool,
+    pub timestamp: i64,
+}
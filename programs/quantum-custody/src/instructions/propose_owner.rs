@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::ProposeOwner>,
+    new_owner: Pubkey,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    ctx.accounts.global_state.check_algorithm(vault.algorithm)?;
+    
+    // Only the PQ key is checked, so a lost or compromised owner wallet can
+    // be replaced without its cooperation
+    let statement = OwnerTransferStatement { new_owner };
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &statement.try_to_vec()?).to_message()?;
+    crypto::authorize(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.pending_owner = Some(new_owner);
+    
+    emit!(OwnerTransferProposedEvent {
+        vault: vault.key(),
+        owner: vault.owner,
+        pending_owner: new_owner,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct OwnerTransferProposedEvent {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
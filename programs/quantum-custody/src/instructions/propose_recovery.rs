@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::ProposeRecovery>, new_key_hash: [u8; 32]) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery = &mut ctx.accounts.recovery;
+    let clock = Clock::get()?;
+    
+    let guardian_index = vault.recovery
+        .guardian_index(&ctx.accounts.guardian.key())
+        .ok_or(QuantumCustodyError::Unauthorized)?;
+    
+    recovery.vault = vault.key();
+    recovery.proposer = ctx.accounts.guardian.key();
+    recovery.new_key_hash = new_key_hash;
+    recovery.guardians_hash = vault.recovery.guardians_hash();
+    recovery.approvals = 0;
+    recovery.approval_count = 0;
+    recovery.unlock_at = None;
+    recovery.expires_at = clock.unix_timestamp.saturating_add(RecoveryProposal::APPROVAL_WINDOW);
+    recovery.created_at = clock.unix_timestamp;
+    recovery.bump = ctx.bumps.recovery;
+    recovery.approve(&vault.recovery, guardian_index, clock.unix_timestamp)?;
+    
+    emit!(RecoveryProposedEvent {
+        vault: vault.key(),
+        proposer: recovery.proposer,
+        new_key_fingerprint: new_key_hash,
+        unlock_at: recovery.unlock_at,
+        expires_at: recovery.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RecoveryProposedEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_key_fingerprint: [u8; 32],
+    pub unlock_at: Option<i64>,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::PublishEncryptionKey>,
+    algorithm: u8,
+    public_key: Vec<u8>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    ctx.accounts.global_state.check_algorithm(vault.algorithm)?;
+    let algorithm = CryptoAlgorithm::from_u8(algorithm)?;
+    let clock = Clock::get()?;
+    
+    require!(algorithm.is_kem(), QuantumCustodyError::InvalidAlgorithm);
+    
+    require!(
+        public_key.len() == algorithm.public_key_size(),
+        QuantumCustodyError::InvalidPublicKeySize
+    );
+    
+    let statement = EncryptionKeyStatement {
+        algorithm,
+        key_hash: hash(&public_key).to_bytes(),
+    };
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &statement.try_to_vec()?).to_message()?;
+    crypto::authorize_with_policy(
+        vault,
+        &signed_message,
+        ctx.accounts.signature_buffer.as_ref(),
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.has_encryption_key = true;
+    
+    let record = &mut ctx.accounts.encryption_key;
+    record.vault = vault.key();
+    record.algorithm = algorithm;
+    record.public_key = public_key;
+    record.updated_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.encryption_key;
+    
+    emit!(EncryptionKeyPublishedEvent {
+        vault: vault.key(),
+        algorithm,
+        key_fingerprint: statement.key_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct EncryptionKeyPublishedEvent {
+    pub vault: Pubkey,
+    pub algorithm: CryptoAlgorithm,
+    pub key_fingerprint: [u8; 32],
+    pub timestamp: i64,
+}
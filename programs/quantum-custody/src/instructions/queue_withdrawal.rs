@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::QueueWithdrawal>,
+    action: VaultAction,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    let (mint, destination, amount) = match action {
+        VaultAction::WithdrawSol { destination, amount } => (None, destination, amount),
+        VaultAction::WithdrawSpl { mint, destination, amount } => (Some(mint), destination, amount),
+        VaultAction::Execute { .. } => return err!(QuantumCustodyError::UnsupportedOperation),
+    };
+    
+    ctx.accounts.vault.check_destination(
+        ctx.accounts.address_book.as_ref(),
+        mint,
+        destination,
+        amount,
+        clock.unix_timestamp,
+    )?;
+    
+    // Over-limit withdrawals fall back to this path, but never unlock sooner
+    // than a limit increase would
+    let vault = &mut ctx.accounts.vault;
+    vault.apply_pending_spending_limits(clock.unix_timestamp);
+    vault.apply_pending_withdrawal_tiers(clock.unix_timestamp);
+    let mut delay = vault.withdrawal_delay(mint, amount);
+    let over_limit = vault.exceeds_spending_limits(mint, amount, clock.unix_timestamp);
+    if over_limit {
+        delay = delay.max(SpendingLimit::DELAY);
+    }
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    
+    crypto::authorize_action(
+        &mut ctx.accounts.vault,
+        &action,
+        nonce,
+        expiry_slot,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.vault = ctx.accounts.vault.key();
+    pending.action = action;
+    pending.payer = ctx.accounts.owner.key();
+    pending.unlock_at = clock.unix_timestamp.saturating_add(delay);
+    pending.created_at = clock.unix_timestamp;
+    pending.over_limit = over_limit;
+    pending.owner_epoch = ctx.accounts.vault.owner_epoch;
+    pending.bump = ctx.bumps.pending_withdrawal;
+    
+    emit!(WithdrawalQueuedEvent {
+        vault: pending.vault,
+        pending_withdrawal: pending.key(),
+        unlock_at: pending.unlock_at,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalQueuedEvent {
+    pub vault: Pubkey,
+    pub pending_withdrawal: Pubkey,
+    pub unlock_at: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
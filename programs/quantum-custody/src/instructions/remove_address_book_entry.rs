@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::RemoveAddressBookEntry>,
+    destination: Pubkey,
+    mint: Option<Pubkey>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    let change = AddressBookChange::Remove { destination, mint };
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &change.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    let address_book = &mut ctx.accounts.address_book;
+    let index = address_book.find(mint, destination)
+        .ok_or(QuantumCustodyError::DestinationNotAllowed)?;
+    address_book.entries.remove(index);
+    
+    emit!(AddressBookEntryRemovedEvent {
+        vault: vault.key(),
+        destination,
+        mint,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AddressBookEntryRemovedEvent {
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+/// Starts the unfreeze delay. PQ-only, since the owner wallet may be what
+/// was compromised; anyone able to freeze can still cancel by re-freezing.
+pub fn handler(ctx: Context<crate::RequestUnfreeze>, nonce: u64, expiry_slot: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    require!(vault.frozen, QuantumCustodyError::VaultNotFrozen);
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, QuantumVault::UNFREEZE_STATEMENT).to_message()?;
+    crypto::authorize(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    let unfreeze_at = clock.unix_timestamp.saturating_add(QuantumVault::UNFREEZE_DELAY);
+    vault.unfreeze_at = Some(unfreeze_at);
+    
+    emit!(VaultUnfreezeRequestedEvent {
+        vault: vault.key(),
+        unfreeze_at,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VaultUnfreezeRequestedEvent {
+    pub vault: Pubkey,
+    pub unfreeze_at: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
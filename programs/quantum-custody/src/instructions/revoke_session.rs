@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Closes a session grant. Only the owner's signature is needed, as
+/// revoking can only reduce what is authorized.
+pub fn handler(ctx: Context<crate::RevokeSession>) -> Result<()> {
+    emit!(SessionRevokedEvent {
+        vault: ctx.accounts.vault.key(),
+        session_grant: ctx.accounts.session_grant.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionRevokedEvent {
+    pub vault: Pubkey,
+    pub session_grant: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,13 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::*;
+use crate::crypto;
 
-pub fn handler(
-    ctx: Context<crate::RotateKeys>,
-    new_public_key: Vec<u8>,
-    signature: Vec<u8>,
-) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
+pub fn handler(ctx: Context<crate::RotateKeys>, nonce: u64, expiry_slot: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    ctx.accounts.global_state.check_algorithm(vault.algorithm)?;
+    let new_public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
     let clock = Clock::get()?;
     
     require!(
@@ -16,18 +15,41 @@ pub fn handler(
     );
     
     require!(
-        signature.len() == vault.algorithm.signature_size(),
+        ctx.accounts.signature_buffer.written as usize == vault.algorithm.signature_size(),
         QuantumCustodyError::InvalidSignatureSize
     );
     
-    vault.public_key = new_public_key.clone();
+    let statement = RotationStatement::new(&vault.public_key, &new_public_key);
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &statement.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let key_history = &mut ctx.accounts.key_history;
+    key_history.vault = ctx.accounts.vault.key();
+    key_history.bump = ctx.bumps.key_history;
+    key_history.record(&ctx.accounts.vault, KeyRetirementReason::Rotation, clock.unix_timestamp);
+    
+    let new_public_key = new_public_key.to_vec();
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.public_key = new_public_key;
     vault.last_key_rotation = clock.unix_timestamp;
+    vault.has_key_history = true;
     
     vault.compliance_status.quantum_readiness_score = 
         vault.compliance_status.quantum_readiness_score.saturating_add(5).min(100);
     
     emit!(KeyRotatedEvent {
         vault: vault.key(),
+        old_key_fingerprint: statement.old_key_hash,
+        new_key_fingerprint: statement.new_key_hash,
+        nonce,
         timestamp: clock.unix_timestamp,
     });
     
@@ -37,5 +59,8 @@ pub fn handler(
 #[event]
 pub struct KeyRotatedEvent {
     pub vault: Pubkey,
+    pub old_key_fingerprint: [u8; 32],
+    pub new_key_fingerprint: [u8; 32],
+    pub nonce: u64,
     pub timestamp: i64,
 }
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program;
+use anchor_spl::{token, token_2022};
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+use crate::instructions::execute::invoke_instructions;
+
+/// Transfer programs, whose instructions are only reachable through
+/// `session_withdraw` so direct transfers are charged against `max_amount`.
+/// Other allowed programs still receive the vault as a signer and can move its
+/// funds by calling these themselves, unmetered; see `SessionScope`.
+const TRANSFER_PROGRAMS: [Pubkey; 3] = [system_program::ID, token::ID, token_2022::ID];
+
+/// Like `execute`, but authorized by a session grant and limited to the
+/// programs in its scope, excluding `TRANSFER_PROGRAMS`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::SessionExecute<'info>>,
+    instructions: Vec<VaultInstruction>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let grant = &ctx.accounts.session_grant;
+    
+    for instruction in &instructions {
+        grant.check_program(&instruction.program_id, clock.slot)?;
+        require!(
+            !TRANSFER_PROGRAMS.contains(&instruction.program_id),
+            QuantumCustodyError::SessionScopeExceeded
+        );
+    }
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    require!(
+        !ctx.accounts.vault.has_transfer_controls(),
+        QuantumCustodyError::WithdrawalQueueRequired
+    );
+    
+    let instructions_hash = hash(&instructions.try_to_vec()?).to_bytes();
+    let action = VaultAction::Execute { instructions_hash };
+    let signed_message =
+        SigningDomain::new(grant.key(), nonce, expiry_slot, &action.try_to_vec()?).to_message()?;
+    crypto::authorize_session(
+        grant,
+        &signed_message,
+        ctx.accounts.signature_buffer.as_ref(),
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    ctx.accounts.vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    let vault = &ctx.accounts.vault;
+    let index = vault.vault_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+    
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(vault.to_account_info());
+    invoke_instructions(&instructions, &account_infos, seeds)?;
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(SessionExecutedEvent {
+        vault: vault.key(),
+        session_grant: grant.key(),
+        instructions_hash,
+        instruction_count: instructions.len() as u32,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionExecutedEvent {
+    pub vault: Pubkey,
+    pub session_grant: Pubkey,
+    pub instructions_hash: [u8; 32],
+    pub instruction_count: u32,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+use crate::instructions::withdraw_sol::transfer_lamports;
+
+/// Withdraws SOL or SPL tokens under a session grant. The vault's own time
+/// locks, spending limits and address book still apply.
+pub fn handler(
+    ctx: Context<crate::SessionWithdraw>,
+    action: VaultAction,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    let (mint, destination, amount, program_id) = match action {
+        VaultAction::WithdrawSol { destination, amount } => (None, destination, amount, system_program::ID),
+        VaultAction::WithdrawSpl { mint, destination, amount } => (Some(mint), destination, amount, token::ID),
+        VaultAction::Execute { .. } => return err!(QuantumCustodyError::UnsupportedOperation),
+    };
+    require_keys_eq!(ctx.accounts.destination.key(), destination, QuantumCustodyError::Unauthorized);
+    
+    let grant = &ctx.accounts.session_grant;
+    grant.check_program(&program_id, clock.slot)?;
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    ctx.accounts.vault.apply_pending_withdrawal_tiers(clock.unix_timestamp);
+    require!(
+        ctx.accounts.vault.withdrawal_delay(mint, amount) == 0,
+        QuantumCustodyError::WithdrawalQueueRequired
+    );
+    
+    let signed_message =
+        SigningDomain::new(grant.key(), nonce, expiry_slot, &action.try_to_vec()?).to_message()?;
+    crypto::authorize_session(
+        grant,
+        &signed_message,
+        ctx.accounts.signature_buffer.as_ref(),
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.check_destination(ctx.accounts.address_book.as_ref(), mint, destination, amount, clock.unix_timestamp)?;
+    vault.record_spend(mint, amount, clock.unix_timestamp, true)?;
+    ctx.accounts.session_grant.record_spend(amount)?;
+    
+    let vault = &ctx.accounts.vault;
+    match mint {
+        None => transfer_lamports(
+            &vault.to_account_info(),
+            &ctx.accounts.destination.to_account_info(),
+            amount,
+        )?,
+        Some(mint) => {
+            let (Some(vault_token_account), Some(token_program)) =
+                (&ctx.accounts.vault_token_account, &ctx.accounts.token_program)
+            else {
+                return err!(QuantumCustodyError::MissingActionAccounts);
+            };
+            require_keys_eq!(vault_token_account.mint, mint, QuantumCustodyError::Unauthorized);
+            vault.check_token_account(&vault.key(), &mint, &vault_token_account.key())?;
+            
+            let index = vault.vault_index.to_le_bytes();
+            let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+            
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault_token_account.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            )?;
+        }
+    }
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(SessionWithdrawnEvent {
+        vault: vault.key(),
+        session_grant: ctx.accounts.session_grant.key(),
+        mint,
+        destination,
+        amount,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionWithdrawnEvent {
+    pub vault: Pubkey,
+    pub session_grant: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::SetAllowedAlgorithms>, allowed_algorithms: u16) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    require!(
+        allowed_algorithms & !GlobalState::ALL_ALGORITHMS == 0,
+        QuantumCustodyError::InvalidAlgorithm
+    );
+    
+    // Vaults on a removed algorithm can still `migrate_algorithm` to a permitted one
+    global_state.allowed_algorithms = allowed_algorithms;
+    
+    emit!(AllowedAlgorithmsSetEvent {
+        allowed_algorithms,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AllowedAlgorithmsSetEvent {
+    pub allowed_algorithms: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<crate::SetAuthority>, new_authority: Pubkey) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    // Takes effect only once `new_authority` signs `accept_authority`
+    global_state.pending_authority = Some(new_authority);
+    
+    emit!(AuthorityTransferProposedEvent {
+        authority: global_state.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
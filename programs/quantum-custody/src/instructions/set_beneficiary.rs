@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+/// Configures or, with `None`, removes the vault's inheritance beneficiary.
+pub fn handler(
+    ctx: Context<crate::SetBeneficiary>,
+    inheritance: Option<InheritanceConfig>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    if let Some(config) = &inheritance {
+        config.validate()?;
+    }
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &inheritance.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.inheritance = inheritance;
+    
+    emit!(BeneficiarySetEvent {
+        vault: vault.key(),
+        beneficiary: inheritance.map(|config| config.beneficiary),
+        inactivity_period: inheritance.map_or(0, |config| config.inactivity_period),
+        claim_delay: inheritance.map_or(0, |config| config.claim_delay),
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BeneficiarySetEvent {
+    pub vault: Pubkey,
+    pub beneficiary: Option<Pubkey>,
+    pub inactivity_period: i64,
+    pub claim_delay: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
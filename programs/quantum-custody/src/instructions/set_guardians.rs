@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::SetGuardians>,
+    recovery: RecoveryConfig,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    recovery.validate()?;
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &recovery.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    
+    emit!(GuardiansSetEvent {
+        vault: vault.key(),
+        guardians: recovery.guardians.clone(),
+        threshold: recovery.threshold,
+        delay: recovery.delay,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    vault.recovery = recovery;
+    
+    Ok(())
+}
+
+#[event]
+pub struct GuardiansSetEvent {
+    pub vault: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub delay: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::SetMinSecurityLevel>, level: u8) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    
+    require!(
+        matches!(level, 2 | 3 | 5),
+        QuantumCustodyError::InvalidSecurityLevel
+    );
+    
+    global_state.min_security_level = level;
+    
+    emit!(MinSecurityLevelSetEvent {
+        level,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct MinSecurityLevelSetEvent {
+    pub level: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+pub fn handler(ctx: Context<crate::SetRotationPolicy>, rotation_policy: RotationPolicy) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    
+    rotation_policy.validate()?;
+    vault.rotation_policy = rotation_policy;
+    
+    emit!(RotationPolicySetEvent {
+        vault: vault.key(),
+        period: rotation_policy.period,
+        grace_period: rotation_policy.grace_period,
+        due_at: vault.rotation_due_at(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RotationPolicySetEvent {
+    pub vault: Pubkey,
+    pub period: i64,
+    pub grace_period: i64,
+    pub due_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+/// Tighter limits apply immediately and discard any staged increase; looser
+/// ones are staged until `SpendingLimit::DELAY` has passed.
+pub fn handler(
+    ctx: Context<crate::SetSpendingLimits>,
+    limits: Vec<SpendingLimitConfig>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    SpendingLimitConfig::validate(&limits)?;
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &limits.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.apply_pending_spending_limits(clock.unix_timestamp);
+    
+    let effective_at = if SpendingLimitConfig::loosens(&vault.spending_limits, &limits) {
+        let effective_at = clock.unix_timestamp.saturating_add(SpendingLimit::DELAY);
+        vault.pending_spending_limits = Some(PendingSpendingLimits {
+            limits: limits.clone(),
+            effective_at,
+        });
+        effective_at
+    } else {
+        vault.set_spending_limits(&limits, clock.unix_timestamp);
+        vault.pending_spending_limits = None;
+        clock.unix_timestamp
+    };
+    
+    emit!(SpendingLimitsSetEvent {
+        vault: vault.key(),
+        limits,
+        effective_at,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SpendingLimitsSetEvent {
+    pub vault: Pubkey,
+    pub limits: Vec<SpendingLimitConfig>,
+    pub effective_at: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::crypto;
+
+/// Tiers that delay no withdrawal less than the current ones apply
+/// immediately and discard any staged change; looser ones are staged for the
+/// longest current delay, so relaxing the time lock takes as long as waiting
+/// it out.
+pub fn handler(
+    ctx: Context<crate::SetWithdrawalTiers>,
+    tiers: Vec<WithdrawalTier>,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    WithdrawalTier::validate(&tiers)?;
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), nonce, expiry_slot, &tiers.try_to_vec()?).to_message()?;
+    crypto::authorize_with_pq_key(
+        vault,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
+    vault.apply_pending_withdrawal_tiers(clock.unix_timestamp);
+    
+    let effective_at = if WithdrawalTier::loosens(&vault.withdrawal_tiers, &tiers) {
+        let longest_delay = vault.withdrawal_tiers.iter().map(|tier| tier.delay).max().unwrap_or(0);
+        let effective_at = clock.unix_timestamp.saturating_add(longest_delay);
+        vault.pending_withdrawal_tiers = Some(PendingWithdrawalTiers {
+            tiers: tiers.clone(),
+            effective_at,
+        });
+        effective_at
+    } else {
+        vault.withdrawal_tiers = tiers.clone();
+        vault.pending_withdrawal_tiers = None;
+        clock.unix_timestamp
+    };
+    
+    emit!(WithdrawalTiersSetEvent {
+        vault: vault.key(),
+        tiers,
+        effective_at,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalTiersSetEvent {
+    pub vault: Pubkey,
+    pub tiers: Vec<WithdrawalTier>,
+    pub effective_at: i64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::*;
+use crate::crypto;
 
 pub fn handler(
     ctx: Context<crate::SignTransaction>,
     message: Vec<u8>,
-    signature: Vec<u8>,
+    nonce: u64,
+    expiry_slot: u64,
 ) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
+    let vault = &ctx.accounts.vault;
+    ctx.accounts.global_state.check_algorithm(vault.algorithm)?;
     let clock = Clock::get()?;
     
     require!(
@@ -15,24 +18,49 @@ pub fn handler(
         QuantumCustodyError::KeyRotationRequired
     );
     
-    require!(
-        signature.len() == vault.algorithm.signature_size(),
-        QuantumCustodyError::InvalidSignatureSize
-    );
+    if let Some(signature_buffer) = &ctx.accounts.signature_buffer {
+        require!(
+            signature_buffer.written as usize == vault.algorithm.signature_size(),
+            QuantumCustodyError::InvalidSignatureSize
+        );
+    }
     
     require!(
         message.len() <= 10_240,
         QuantumCustodyError::MessageTooLarge
     );
     
+    let signed_message = SigningDomain::new(vault.key(), nonce, expiry_slot, &message).to_message()?;
+    crypto::authorize_with_policy(
+        vault,
+        &signed_message,
+        ctx.accounts.signature_buffer.as_ref(),
+        ctx.accounts.verification_session.as_ref(),
+        &ctx.accounts.instructions,
+    )?;
+    
+    if vault.is_key_rotation_due_soon(clock.unix_timestamp) {
+        emit!(KeyRotationDueEvent {
+            vault: vault.key(),
+            due_at: vault.rotation_due_at(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    let vault = &mut ctx.accounts.vault;
+    vault.consume_nonce(nonce, expiry_slot, &clock)?;
     vault.transaction_count = vault.transaction_count
         .checked_add(1)
         .ok_or(QuantumCustodyError::MathOverflow)?;
     
+    ctx.accounts.global_state.record_transaction()?;
+    
     emit!(TransactionSignedEvent {
         vault: vault.key(),
         transaction_count: vault.transaction_count,
+        nonce,
         algorithm: vault.algorithm,
+        signature_policy: vault.signature_policy,
         timestamp: clock.unix_timestamp,
     });
     
@@ -43,6 +71,15 @@ pub fn handler(
 pub struct TransactionSignedEvent {
     pub vault: Pubkey,
     pub transaction_count: u64,
+    pub nonce: u64,
     pub algorithm: CryptoAlgorithm,
+    pub signature_policy: SignaturePolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeyRotationDueEvent {
+    pub vault: Pubkey,
+    pub due_at: i64,
     pub timestamp: i64,
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+/// Starts a beneficiary claim on a vault inactive for its configured period.
+/// The signature binds the vault's current nonce, so it goes stale as soon as
+/// the vault signs anything; the nonce itself is left for the owner.
+pub fn handler(ctx: Context<crate::StartInheritanceClaim>, expiry_slot: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    let config = vault.inheritance.ok_or(QuantumCustodyError::Unauthorized)?;
+    require_keys_eq!(
+        ctx.accounts.beneficiary.key(),
+        config.beneficiary,
+        QuantumCustodyError::Unauthorized
+    );
+    require!(clock.slot <= expiry_slot, QuantumCustodyError::SignatureExpired);
+    require!(
+        clock.unix_timestamp >= vault.last_active_at().saturating_add(config.inactivity_period),
+        QuantumCustodyError::VaultStillActive
+    );
+    
+    let public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    require!(
+        hash(&public_key).to_bytes() == config.key_hash,
+        QuantumCustodyError::Unauthorized
+    );
+    
+    let signed_message =
+        SigningDomain::new(vault.key(), vault.nonce, expiry_slot, InheritanceClaim::CLAIM_STATEMENT)
+            .to_message()?;
+    crypto::authorize_key(
+        vault.key(),
+        config.algorithm,
+        &public_key,
+        &signed_message,
+        &ctx.accounts.signature_buffer,
+        Some(&ctx.accounts.verification_session),
+    )?;
+    
+    let claim = &mut ctx.accounts.claim;
+    claim.vault = vault.key();
+    claim.beneficiary = config.beneficiary;
+    claim.started_at = clock.unix_timestamp;
+    claim.unlock_at = clock.unix_timestamp.saturating_add(config.claim_delay);
+    claim.activity_count = vault.activity_count;
+    claim.bump = ctx.bumps.claim;
+    
+    emit!(InheritanceClaimStartedEvent {
+        vault: vault.key(),
+        beneficiary: claim.beneficiary,
+        last_active_at: vault.last_active_at(),
+        unlock_at: claim.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct InheritanceClaimStartedEvent {
+    pub vault: Pubkey,
+    pub beneficiary: Pubkey,
+    pub last_active_at: i64,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::start_verification::VerificationStartedEvent;
+
+/// Starts verifying a signature by a key other than the vault's own, such as
+/// a PQ session key or the beneficiary's key, acting for `subject`.
+pub fn handler(
+    ctx: Context<crate::StartKeyVerification>,
+    algorithm: CryptoAlgorithm,
+    message: Vec<u8>,
+) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    
+    session.begin(
+        ctx.accounts.subject.key(),
+        &ctx.accounts.signature_buffer,
+        algorithm,
+        &public_key,
+        &message,
+        ctx.bumps.session,
+    )?;
+    
+    emit!(VerificationStartedEvent {
+        session: session.key(),
+        vault: session.vault,
+        total_steps: session.total_steps,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::*;
+use crate::error::*;
+use crate::instructions::start_verification::VerificationStartedEvent;
+
+pub fn handler(ctx: Context<crate::StartMemberVerification>, member_index: u8) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    let session = &mut ctx.accounts.session;
+    
+    let member = *multisig.members
+        .get(member_index as usize)
+        .ok_or(QuantumCustodyError::InvalidMembers)?;
+    
+    let public_key = SignatureBuffer::payload(&ctx.accounts.key_buffer)?;
+    require!(
+        hash(&public_key).to_bytes() == member.key_hash,
+        QuantumCustodyError::Unauthorized
+    );
+    
+    session.begin(
+        multisig.key(),
+        &ctx.accounts.signature_buffer,
+        member.algorithm,
+        &public_key,
+        &proposal.signing_message()?,
+        ctx.bumps.session,
+    )?;
+    
+    emit!(VerificationStartedEvent {
+        session: session.key(),
+        vault: session.vault,
+        total_steps: session.total_steps,
+    });
+    
+    Ok(())
+}
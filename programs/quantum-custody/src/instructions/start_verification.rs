@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub fn handler(ctx: Context<crate::StartVerification>, message: Vec<u8>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let session = &mut ctx.accounts.session;
+    
+    session.begin(
+        vault.key(),
+        &ctx.accounts.signature_buffer,
+        vault.algorithm,
+        &vault.public_key,
+        &message,
+        ctx.bumps.session,
+    )?;
+    
+    emit!(VerificationStartedEvent {
+        session: session.key(),
+        vault: session.vault,
+        total_steps: session.total_steps,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VerificationStartedEvent {
+    pub session: Pubkey,
+    pub vault: Pubkey,
+    pub total_steps: u32,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Lifts a freeze once a PQ-signed request has waited out its delay.
+/// Callable by anyone, as the request itself carries the authorization.
+pub fn handler(ctx: Context<crate::UnfreezeVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    require!(vault.frozen, QuantumCustodyError::VaultNotFrozen);
+    
+    let unfreeze_at = vault.unfreeze_at.ok_or(QuantumCustodyError::Unauthorized)?;
+    require!(clock.unix_timestamp >= unfreeze_at, QuantumCustodyError::UnfreezeLocked);
+    
+    vault.frozen = false;
+    vault.unfreeze_at = None;
+    
+    emit!(VaultUnfrozenEvent {
+        vault: vault.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VaultUnfrozenEvent {
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::WithdrawSol>,
+    amount: u64,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let destination = ctx.accounts.destination.key();
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    ctx.accounts.vault.apply_pending_withdrawal_tiers(Clock::get()?.unix_timestamp);
+    require!(
+        ctx.accounts.vault.withdrawal_delay(None, amount) == 0,
+        QuantumCustodyError::WithdrawalQueueRequired
+    );
+    
+    crypto::authorize_action(
+        &mut ctx.accounts.vault,
+        &VaultAction::WithdrawSol { destination, amount },
+        nonce,
+        expiry_slot,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.vault.check_destination(ctx.accounts.address_book.as_ref(), None, destination, amount, now)?;
+    ctx.accounts.vault.record_spend(None, amount, now, true)?;
+    
+    transfer_lamports(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        amount,
+    )?;
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(SolWithdrawnEvent {
+        vault: ctx.accounts.vault.key(),
+        destination,
+        amount,
+        nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Moves lamports out of a program-owned vault account, never dipping below
+/// its rent-exempt minimum.
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(from.data_len());
+    let available = from.lamports().saturating_sub(rent_exempt_minimum);
+    
+    require!(amount <= available, QuantumCustodyError::InsufficientVaultBalance);
+    
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    
+    Ok(())
+}
+
+#[event]
+pub struct SolWithdrawnEvent {
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::crypto;
+
+pub fn handler(
+    ctx: Context<crate::WithdrawSpl>,
+    amount: u64,
+    nonce: u64,
+    expiry_slot: u64,
+) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let destination = ctx.accounts.destination.key();
+    
+    ctx.accounts.global_state.check_algorithm(ctx.accounts.vault.algorithm)?;
+    ctx.accounts.vault.apply_pending_withdrawal_tiers(Clock::get()?.unix_timestamp);
+    require!(
+        ctx.accounts.vault.withdrawal_delay(Some(mint), amount) == 0,
+        QuantumCustodyError::WithdrawalQueueRequired
+    );
+    
+    crypto::authorize_action(
+        &mut ctx.accounts.vault,
+        &VaultAction::WithdrawSpl { mint, destination, amount },
+        nonce,
+        expiry_slot,
+        &ctx.accounts.signature_buffer,
+        ctx.accounts.verification_session.as_ref(),
+    )?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.vault.check_destination(
+        ctx.accounts.address_book.as_ref(),
+        Some(mint),
+        destination,
+        amount,
+        now,
+    )?;
+    ctx.accounts.vault.record_spend(Some(mint), amount, now, true)?;
+    
+    let vault = &ctx.accounts.vault;
+    let index = vault.vault_index.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"vault", vault.creator.as_ref(), &index, &[vault.bump]];
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+    
+    ctx.accounts.global_state.record_transaction()?;
+    
+    emit!(SplWithdrawnEvent {
+        vault: vault.key(),
+        mint,
+        destination,
+        amount,
+        nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SplWithdrawnEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
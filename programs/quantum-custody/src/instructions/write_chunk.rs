@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+pub fn handler(ctx: Context<crate::WriteChunk>, offset: u32, chunk: Vec<u8>) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    
+    require!(!buffer.finalized, QuantumCustodyError::BufferAlreadyFinalized);
+    
+    let end = offset
+        .checked_add(chunk.len() as u32)
+        .ok_or(QuantumCustodyError::MathOverflow)?;
+    
+    require!(
+        offset == buffer.written && !chunk.is_empty() && end <= buffer.total_len,
+        QuantumCustodyError::InvalidBufferWrite
+    );
+    
+    {
+        let start = SignatureBuffer::HEADER_SIZE + offset as usize;
+        let info = buffer.to_account_info();
+        let mut data = info.try_borrow_mut_data()?;
+        data[start..start + chunk.len()].copy_from_slice(&chunk);
+    }
+    
+    buffer.written = end;
+    
+    Ok(())
+}
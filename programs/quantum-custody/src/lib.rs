@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
+pub mod crypto;
 pub mod error;
 pub mod instructions;
 pub mod state;
 
-use instructions::*;
-
 declare_id!("QCust1111111111111111111111111111111111111");
 
 #[program]
@@ -20,24 +22,361 @@ pub mod quantum_custody {
         ctx: Context<CreateVault>,
         algorithm: u8,
         public_key: Vec<u8>,
+        signature_policy: state::SignaturePolicy,
+        label: String,
+        rotation_policy: Option<state::RotationPolicy>,
     ) -> Result<()> {
-        instructions::create_vault::handler(ctx, algorithm, public_key)
+        instructions::create_vault::handler(
+            ctx,
+            algorithm,
+            public_key,
+            signature_policy,
+            label,
+            rotation_policy,
+        )
     }
 
     pub fn sign_transaction(
         ctx: Context<SignTransaction>,
         message: Vec<u8>,
-        signature: Vec<u8>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::sign_transaction::handler(ctx, message, nonce, expiry_slot)
+    }
+
+    pub fn rotate_keys(ctx: Context<RotateKeys>, nonce: u64, expiry_slot: u64) -> Result<()> {
+        instructions::rotate_keys::handler(ctx, nonce, expiry_slot)
+    }
+
+    pub fn migrate_algorithm(
+        ctx: Context<MigrateAlgorithm>,
+        algorithm: u8,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::migrate_algorithm::handler(ctx, algorithm, nonce, expiry_slot)
+    }
+
+    pub fn publish_encryption_key(
+        ctx: Context<PublishEncryptionKey>,
+        algorithm: u8,
+        public_key: Vec<u8>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::publish_encryption_key::handler(ctx, algorithm, public_key, nonce, expiry_slot)
+    }
+
+    pub fn set_rotation_policy(
+        ctx: Context<SetRotationPolicy>,
+        rotation_policy: state::RotationPolicy,
+    ) -> Result<()> {
+        instructions::set_rotation_policy::handler(ctx, rotation_policy)
+    }
+
+    pub fn close_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseVault<'info>>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::close_vault::handler(ctx, nonce, expiry_slot)
+    }
+
+    pub fn propose_owner(
+        ctx: Context<ProposeOwner>,
+        new_owner: Pubkey,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::propose_owner::handler(ctx, new_owner, nonce, expiry_slot)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner::handler(ctx)
+    }
+
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        recovery: state::RecoveryConfig,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::set_guardians::handler(ctx, recovery, nonce, expiry_slot)
+    }
+
+    pub fn propose_recovery(ctx: Context<ProposeRecovery>, new_key_hash: [u8; 32]) -> Result<()> {
+        instructions::propose_recovery::handler(ctx, new_key_hash)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        instructions::approve_recovery::handler(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>, nonce: u64, expiry_slot: u64) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx, nonce, expiry_slot)
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        instructions::execute_recovery::handler(ctx)
+    }
+
+    pub fn close_recovery(ctx: Context<CloseRecovery>) -> Result<()> {
+        instructions::close_recovery::handler(ctx)
+    }
+
+    pub fn set_withdrawal_tiers(
+        ctx: Context<SetWithdrawalTiers>,
+        tiers: Vec<state::WithdrawalTier>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::set_withdrawal_tiers::handler(ctx, tiers, nonce, expiry_slot)
+    }
+
+    pub fn set_spending_limits(
+        ctx: Context<SetSpendingLimits>,
+        limits: Vec<state::SpendingLimitConfig>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::set_spending_limits::handler(ctx, limits, nonce, expiry_slot)
+    }
+
+    pub fn add_address_book_entry(
+        ctx: Context<AddAddressBookEntry>,
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+        cap: Option<u64>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::add_address_book_entry::handler(ctx, destination, mint, cap, nonce, expiry_slot)
+    }
+
+    pub fn remove_address_book_entry(
+        ctx: Context<RemoveAddressBookEntry>,
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::remove_address_book_entry::handler(ctx, destination, mint, nonce, expiry_slot)
+    }
+
+    pub fn queue_withdrawal(
+        ctx: Context<QueueWithdrawal>,
+        action: state::VaultAction,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::queue_withdrawal::handler(ctx, action, nonce, expiry_slot)
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        instructions::execute_withdrawal::handler(ctx)
+    }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>, nonce: u64, expiry_slot: u64) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx, nonce, expiry_slot)
+    }
+
+    pub fn grant_session(
+        ctx: Context<GrantSession>,
+        session_key: state::SessionKey,
+        scope: state::SessionScope,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::grant_session::handler(ctx, session_key, scope, nonce, expiry_slot)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::handler(ctx)
+    }
+
+    pub fn session_withdraw(
+        ctx: Context<SessionWithdraw>,
+        action: state::VaultAction,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::session_withdraw::handler(ctx, action, nonce, expiry_slot)
+    }
+
+    pub fn session_execute<'info>(
+        ctx: Context<'_, '_, '_, 'info, SessionExecute<'info>>,
+        instructions: Vec<state::VaultInstruction>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::session_execute::handler(ctx, instructions, nonce, expiry_slot)
+    }
+
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        inheritance: Option<state::InheritanceConfig>,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::set_beneficiary::handler(ctx, inheritance, nonce, expiry_slot)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::handler(ctx)
+    }
+
+    pub fn start_inheritance_claim(ctx: Context<StartInheritanceClaim>, expiry_slot: u64) -> Result<()> {
+        instructions::start_inheritance_claim::handler(ctx, expiry_slot)
+    }
+
+    pub fn complete_inheritance_claim(ctx: Context<CompleteInheritanceClaim>) -> Result<()> {
+        instructions::complete_inheritance_claim::handler(ctx)
+    }
+
+    pub fn freeze_vault(ctx: Context<FreezeVault>) -> Result<()> {
+        instructions::freeze_vault::handler(ctx)
+    }
+
+    pub fn request_unfreeze(ctx: Context<RequestUnfreeze>, nonce: u64, expiry_slot: u64) -> Result<()> {
+        instructions::request_unfreeze::handler(ctx, nonce, expiry_slot)
+    }
+
+    pub fn unfreeze_vault(ctx: Context<UnfreezeVault>) -> Result<()> {
+        instructions::unfreeze_vault::handler(ctx)
+    }
+
+    pub fn init_buffer(ctx: Context<InitBuffer>, buffer_id: u32, total_len: u32) -> Result<()> {
+        instructions::init_buffer::handler(ctx, buffer_id, total_len)
+    }
+
+    pub fn write_chunk(ctx: Context<WriteChunk>, offset: u32, chunk: Vec<u8>) -> Result<()> {
+        instructions::write_chunk::handler(ctx, offset, chunk)
+    }
+
+    pub fn finalize(ctx: Context<Finalize>) -> Result<()> {
+        instructions::finalize::handler(ctx)
+    }
+
+    pub fn close_buffer(ctx: Context<CloseBuffer>) -> Result<()> {
+        instructions::close_buffer::handler(ctx)
+    }
+
+    pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+        instructions::close_session::handler(ctx)
+    }
+
+    pub fn start_verification(ctx: Context<StartVerification>, message: Vec<u8>) -> Result<()> {
+        instructions::start_verification::handler(ctx, message)
+    }
+
+    pub fn continue_verification(ctx: Context<ContinueVerification>, steps: u16) -> Result<()> {
+        instructions::continue_verification::handler(ctx, steps)
+    }
+
+    pub fn start_key_verification(
+        ctx: Context<StartKeyVerification>,
+        algorithm: state::CryptoAlgorithm,
+        message: Vec<u8>,
+    ) -> Result<()> {
+        instructions::start_key_verification::handler(ctx, algorithm, message)
+    }
+
+    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        instructions::deposit_sol::handler(ctx, amount)
+    }
+
+    pub fn withdraw_sol(
+        ctx: Context<WithdrawSol>,
+        amount: u64,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::withdraw_sol::handler(ctx, amount, nonce, expiry_slot)
+    }
+
+    pub fn withdraw_spl(
+        ctx: Context<WithdrawSpl>,
+        amount: u64,
+        nonce: u64,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::withdraw_spl::handler(ctx, amount, nonce, expiry_slot)
+    }
+
+    pub fn open_token_account(ctx: Context<OpenTokenAccount>) -> Result<()> {
+        instructions::open_token_account::handler(ctx)
+    }
+
+    pub fn execute<'info>(
+        ctx: Context<'_, '_, '_, 'info, Execute<'info>>,
+        instructions: Vec<state::VaultInstruction>,
+        nonce: u64,
+        expiry_slot: u64,
     ) -> Result<()> {
-        instructions::sign_transaction::handler(ctx, message, signature)
+        instructions::execute::handler(ctx, instructions, nonce, expiry_slot)
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        multisig_id: u64,
+        threshold: u8,
+        members: Vec<state::MultisigMember>,
+    ) -> Result<()> {
+        instructions::create_multisig::handler(ctx, multisig_id, threshold, members)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: state::VaultAction,
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::create_proposal::handler(ctx, action, expiry_slot)
+    }
+
+    pub fn start_member_verification(
+        ctx: Context<StartMemberVerification>,
+        member_index: u8,
+    ) -> Result<()> {
+        instructions::start_member_verification::handler(ctx, member_index)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>, member_index: u8) -> Result<()> {
+        instructions::approve_proposal::handler(ctx, member_index)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+        instructions: Vec<state::VaultInstruction>,
+    ) -> Result<()> {
+        instructions::execute_proposal::handler(ctx, instructions)
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::close_proposal::handler(ctx)
     }
 
-    pub fn rotate_keys(
-        ctx: Context<RotateKeys>,
-        new_public_key: Vec<u8>,
-        signature: Vec<u8>,
+    pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::set_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    pub fn pause_protocol(ctx: Context<PauseProtocol>, paused: bool) -> Result<()> {
+        instructions::pause_protocol::handler(ctx, paused)
+    }
+
+    pub fn set_min_security_level(ctx: Context<SetMinSecurityLevel>, level: u8) -> Result<()> {
+        instructions::set_min_security_level::handler(ctx, level)
+    }
+
+    pub fn set_allowed_algorithms(
+        ctx: Context<SetAllowedAlgorithms>,
+        allowed_algorithms: u16,
     ) -> Result<()> {
-        instructions::rotate_keys::handler(ctx, new_public_key, signature)
+        instructions::set_allowed_algorithms::handler(ctx, allowed_algorithms)
     }
 }
 
@@ -60,11 +399,28 @@ pub struct Initialize<'info> {
 
 #[derive(Accounts)]
 pub struct CreateVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + state::VaultRegistry::SPACE,
+        seeds = [b"registry", owner.key().as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, state::VaultRegistry>,
+    
     #[account(
         init,
         payer = owner,
         space = 8 + state::QuantumVault::SPACE,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", owner.key().as_ref(), &registry.vault_count.to_le_bytes()],
         bump
     )]
     pub vault: Account<'info, state::QuantumVault>,
@@ -79,24 +435,2024 @@ pub struct CreateVault<'info> {
 pub struct SignTransaction<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
-        bump,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
         has_one = owner
     )]
     pub vault: Account<'info, state::QuantumVault>,
     
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Option<Account<'info, state::SignatureBuffer>>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = signature_buffer.as_ref().is_some_and(|buffer| buffer.key() == verification_session.signature_buffer)
+            @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
     pub owner: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct RotateKeys<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
         has_one = owner
     )]
     pub vault: Account<'info, state::QuantumVault>,
     
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + state::KeyHistory::SPACE,
+        seeds = [b"key_history", vault.key().as_ref()],
+        bump
+    )]
+    pub key_history: Account<'info, state::KeyHistory>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAlgorithm<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner,
+        realloc = 8 + state::QuantumVault::SPACE,
+        realloc::payer = owner,
+        realloc::zero = false
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + state::KeyHistory::SPACE,
+        seeds = [b"key_history", vault.key().as_ref()],
+        bump
+    )]
+    pub key_history: Account<'info, state::KeyHistory>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PublishEncryptionKey<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + state::EncryptionKeyRecord::SPACE,
+        seeds = [b"encryption_key", vault.key().as_ref()],
+        bump
+    )]
+    pub encryption_key: Account<'info, state::EncryptionKeyRecord>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Option<Account<'info, state::SignatureBuffer>>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = signature_buffer.as_ref().is_some_and(|buffer| buffer.key() == verification_session.signature_buffer)
+            @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
     pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRotationPolicy<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"encryption_key", vault.key().as_ref()],
+        bump = encryption_key.bump
+    )]
+    pub encryption_key: Option<Account<'info, state::EncryptionKeyRecord>>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"key_history", vault.key().as_ref()],
+        bump = key_history.bump
+    )]
+    pub key_history: Option<Account<'info, state::KeyHistory>>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        constraint = vault.pending_owner == Some(new_owner.key()) @ error::QuantumCustodyError::Unauthorized
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    pub new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeRecovery<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + state::RecoveryProposal::SPACE,
+        seeds = [b"recovery", vault.key().as_ref(), guardian.key().as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, state::RecoveryProposal>,
+    
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"recovery", vault.key().as_ref(), recovery.proposer.as_ref()],
+        bump = recovery.bump,
+        has_one = vault
+    )]
+    pub recovery: Account<'info, state::RecoveryProposal>,
+    
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"recovery", vault.key().as_ref(), recovery.proposer.as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+        has_one = proposer
+    )]
+    pub recovery: Account<'info, state::RecoveryProposal>,
+    
+    /// CHECK: receives the proposal's rent; matched against `recovery.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + state::KeyHistory::SPACE,
+        seeds = [b"key_history", vault.key().as_ref()],
+        bump
+    )]
+    pub key_history: Account<'info, state::KeyHistory>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"recovery", vault.key().as_ref(), recovery.proposer.as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+        has_one = proposer
+    )]
+    pub recovery: Account<'info, state::RecoveryProposal>,
+    
+    /// CHECK: receives the proposal's rent; matched against `recovery.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = key_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRecovery<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"recovery", vault.key().as_ref(), recovery.proposer.as_ref()],
+        bump = recovery.bump,
+        has_one = vault,
+        has_one = proposer
+    )]
+    pub recovery: Account<'info, state::RecoveryProposal>,
+    
+    /// CHECK: receives the proposal's rent; matched against `recovery.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetWithdrawalTiers<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddAddressBookEntry<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + state::AddressBook::SPACE,
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump
+    )]
+    pub address_book: Account<'info, state::AddressBook>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAddressBookEntry<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Account<'info, state::AddressBook>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetSpendingLimits<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: state::VaultAction, nonce: u64)]
+pub struct QueueWithdrawal<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + state::PendingWithdrawal::SPACE,
+        seeds = [b"withdrawal", vault.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, state::PendingWithdrawal>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    #[account(
+        mut,
+        close = payer,
+        has_one = vault,
+        has_one = payer,
+        constraint = pending_withdrawal.owner_epoch == vault.owner_epoch @ error::QuantumCustodyError::OwnershipChanged
+    )]
+    pub pending_withdrawal: Account<'info, state::PendingWithdrawal>,
+    
+    /// CHECK: matched against the queued withdrawal's destination
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ error::QuantumCustodyError::Unauthorized
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: receives the pending account's rent; matched by `has_one`
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = payer,
+        has_one = vault,
+        has_one = payer
+    )]
+    pub pending_withdrawal: Account<'info, state::PendingWithdrawal>,
+    
+    /// CHECK: receives the pending account's rent; matched by `has_one`
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = submitter,
+        constraint = signature_buffer.authority == submitter.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = submitter,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(session_key: state::SessionKey, scope: state::SessionScope, nonce: u64)]
+pub struct GrantSession<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + state::SessionGrant::space(&session_key),
+        seeds = [b"session_grant", vault.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub session_grant: Account<'info, state::SessionGrant>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    // Deliberately usable while the protocol is paused
+    #[account(seeds = [b"global"], bump = global_state.bump)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(mut, close = owner, has_one = vault)]
+    pub session_grant: Account<'info, state::SessionGrant>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SessionWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        has_one = vault,
+        constraint = session_grant.owner_epoch == vault.owner_epoch @ error::QuantumCustodyError::OwnershipChanged
+    )]
+    pub session_grant: Account<'info, state::SessionGrant>,
+    
+    #[account(
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    /// CHECK: matched against the signed action's destination
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.owner == vault.key() @ error::QuantumCustodyError::Unauthorized
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Option<Account<'info, state::SignatureBuffer>>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = verification_session.vault == session_grant.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = signature_buffer.as_ref().is_some_and(|buffer| buffer.key() == verification_session.signature_buffer)
+            @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct SessionExecute<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        has_one = vault,
+        constraint = session_grant.owner_epoch == vault.owner_epoch @ error::QuantumCustodyError::OwnershipChanged
+    )]
+    pub session_grant: Account<'info, state::SessionGrant>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Option<Account<'info, state::SignatureBuffer>>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = verification_session.vault == session_grant.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = signature_buffer.as_ref().is_some_and(|buffer| buffer.key() == verification_session.signature_buffer)
+            @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    /// CHECK: constrained to the instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StartInheritanceClaim<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        space = 8 + state::InheritanceClaim::SPACE,
+        seeds = [b"inheritance_claim", vault.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, state::InheritanceClaim>,
+    
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == beneficiary.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = signature_buffer.authority == beneficiary.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Account<'info, state::VerificationSession>,
+    
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteInheritanceClaim<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        space = 8 + state::KeyHistory::SPACE,
+        seeds = [b"key_history", vault.key().as_ref()],
+        bump
+    )]
+    pub key_history: Account<'info, state::KeyHistory>,
+    
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [b"inheritance_claim", vault.key().as_ref()],
+        bump = claim.bump,
+        has_one = vault,
+        has_one = beneficiary
+    )]
+    pub claim: Account<'info, state::InheritanceClaim>,
+    
+    #[account(
+        mut,
+        close = beneficiary,
+        constraint = key_buffer.authority == beneficiary.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeVault<'info> {
+    // Deliberately usable while the protocol is paused
+    #[account(seeds = [b"global"], bump = global_state.bump)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnfreeze<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = payer,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeVault<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+}
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u32)]
+pub struct InitBuffer<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = state::SignatureBuffer::HEADER_SIZE,
+        seeds = [b"buffer", authority.key().as_ref(), &buffer_id.to_le_bytes()],
+        bump
+    )]
+    pub buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(offset: u32, chunk: Vec<u8>)]
+pub struct WriteChunk<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        has_one = authority,
+        realloc = state::SignatureBuffer::HEADER_SIZE + offset as usize + chunk.len(),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Finalize<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut, has_one = authority)]
+    pub buffer: Account<'info, state::SignatureBuffer>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBuffer<'info> {
+    // Deliberately usable while the protocol is paused
+    #[account(seeds = [b"global"], bump = global_state.bump)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut, close = authority, has_one = authority)]
+    pub buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    // Deliberately usable while the protocol is paused
+    #[account(seeds = [b"global"], bump = global_state.bump)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut, close = payer, has_one = payer)]
+    pub session: Account<'info, state::VerificationSession>,
+    
+    /// Absent only if already closed with `close_buffer`
+    #[account(
+        mut,
+        close = payer,
+        constraint = signature_buffer.key() == session.signature_buffer @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized
+    )]
+    pub signature_buffer: Option<Account<'info, state::SignatureBuffer>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StartVerification<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + state::VerificationSession::SPACE,
+        seeds = [b"session", vault.key().as_ref(), signature_buffer.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, state::VerificationSession>,
+    
+    /// Uploader of the signature; need not be the vault owner, since a session
+    /// only proves the signature valid and grants no authority by itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ContinueVerification<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut, has_one = signature_buffer)]
+    pub session: Account<'info, state::VerificationSession>,
+    
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+}
+
+#[derive(Accounts)]
+pub struct StartKeyVerification<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    /// CHECK: only namespaces the session; the key and message it verified are
+    /// matched against the subject's own records when the session is consumed
+    pub subject: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        constraint = signature_buffer.authority == payer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + state::VerificationSession::SPACE,
+        seeds = [b"session", subject.key().as_ref(), signature_buffer.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, state::VerificationSession>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositSol<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    /// CHECK: only receives lamports; bound into the PQ-signed withdrawal
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSpl<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        seeds = [b"address_book", vault.key().as_ref()],
+        bump = address_book.bump
+    )]
+    pub address_book: Option<Account<'info, state::AddressBook>>,
+    
+    #[account(constraint = vault.token_mints.contains(&mint.key()) @ error::QuantumCustodyError::TokenAccountNotOpened)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::mint = mint)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OpenTokenAccount<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = vault
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), &vault.vault_index.to_le_bytes()],
+        bump = vault.bump,
+        constraint = !vault.frozen @ error::QuantumCustodyError::VaultFrozen,
+        has_one = owner
+    )]
+    pub vault: Account<'info, state::QuantumVault>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = signature_buffer.authority == owner.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = owner,
+        constraint = verification_session.vault == vault.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(multisig_id: u64)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + state::MultisigVault::SPACE,
+        seeds = [b"multisig", creator.key().as_ref(), &multisig_id.to_le_bytes()],
+        bump
+    )]
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(mut)]
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + state::MultisigProposal::SPACE,
+        seeds = [b"proposal", multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, state::MultisigProposal>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StartMemberVerification<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(has_one = multisig)]
+    pub proposal: Account<'info, state::MultisigProposal>,
+    
+    #[account(
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == approver.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        constraint = signature_buffer.authority == approver.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        init,
+        payer = approver,
+        space = 8 + state::VerificationSession::SPACE,
+        seeds = [b"session", multisig.key().as_ref(), signature_buffer.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, state::VerificationSession>,
+    
+    #[account(mut)]
+    pub approver: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(mut, has_one = multisig)]
+    pub proposal: Account<'info, state::MultisigProposal>,
+    
+    #[account(
+        mut,
+        close = approver,
+        constraint = key_buffer.key() != signature_buffer.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.authority == approver.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = key_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub key_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = approver,
+        constraint = signature_buffer.authority == approver.key() @ error::QuantumCustodyError::Unauthorized,
+        constraint = signature_buffer.finalized @ error::QuantumCustodyError::BufferNotFinalized
+    )]
+    pub signature_buffer: Account<'info, state::SignatureBuffer>,
+    
+    #[account(
+        mut,
+        close = approver,
+        constraint = verification_session.vault == multisig.key() @ error::QuantumCustodyError::VerificationSessionMismatch,
+        constraint = verification_session.signature_buffer == signature_buffer.key() @ error::QuantumCustodyError::VerificationSessionMismatch
+    )]
+    pub verification_session: Option<Account<'info, state::VerificationSession>>,
+    
+    #[account(mut)]
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref(), &multisig.multisig_id.to_le_bytes()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(mut, has_one = multisig, has_one = proposer, close = proposer)]
+    pub proposal: Account<'info, state::MultisigProposal>,
+    
+    /// CHECK: receives the proposal's rent; matched against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    pub executor: Signer<'info>,
+    
+    /// CHECK: only receives lamports; matched against the approved action
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub multisig_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = !global_state.paused @ error::QuantumCustodyError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref(), &multisig.multisig_id.to_le_bytes()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, state::MultisigVault>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, state::MultisigProposal>,
+    
+    /// CHECK: receives the proposal's rent; matched against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuthority<'info> {
+    #[account(mut, seeds = [b"global"], bump = global_state.bump, has_one = authority)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"global"],
+        bump = global_state.bump,
+        constraint = global_state.pending_authority == Some(new_authority.key())
+            @ error::QuantumCustodyError::Unauthorized
+    )]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    #[account(mut, seeds = [b"global"], bump = global_state.bump, has_one = authority)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMinSecurityLevel<'info> {
+    #[account(mut, seeds = [b"global"], bump = global_state.bump, has_one = authority)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAllowedAlgorithms<'info> {
+    #[account(mut, seeds = [b"global"], bump = global_state.bump, has_one = authority)]
+    pub global_state: Account<'info, state::GlobalState>,
+    
+    pub authority: Signer<'info>,
 }
@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::associated_token::get_associated_token_address;
+use std::cell::Ref;
+use crate::crypto::{self, dilithium, sphincs};
+use crate::error::QuantumCustodyError;
 
 #[account]
 pub struct GlobalState {
@@ -6,10 +12,52 @@ pub struct GlobalState {
     pub total_vaults: u64,
     pub total_transactions: u64,
     pub bump: u8,
+    pub pending_authority: Option<Pubkey>,
+    pub paused: bool,
+    pub min_security_level: u8,
+    pub allowed_algorithms: u16,
 }
 
 impl GlobalState {
-    pub const SPACE: usize = 32 + 8 + 8 + 1;
+    pub const SPACE: usize = 32 + 8 + 8 + 1 + 33 + 1 + 1 + 2;
+    /// Allowlist bitmap with every `CryptoAlgorithm` permitted.
+    pub const ALL_ALGORITHMS: u16 = (1 << 10) - 1;
+    
+    pub fn record_vault(&mut self) -> Result<()> {
+        self.total_vaults = self.total_vaults
+            .checked_add(1)
+            .ok_or(QuantumCustodyError::MathOverflow)?;
+        Ok(())
+    }
+    
+    pub fn record_vault_closed(&mut self) -> Result<()> {
+        self.total_vaults = self.total_vaults
+            .checked_sub(1)
+            .ok_or(QuantumCustodyError::MathOverflow)?;
+        Ok(())
+    }
+    
+    pub fn record_transaction(&mut self) -> Result<()> {
+        self.total_transactions = self.total_transactions
+            .checked_add(1)
+            .ok_or(QuantumCustodyError::MathOverflow)?;
+        Ok(())
+    }
+    
+    pub fn is_allowed(&self, algorithm: CryptoAlgorithm) -> bool {
+        self.allowed_algorithms & (1 << algorithm as u16) != 0
+    }
+    
+    /// Rejects algorithms governance has removed from the allowlist or that
+    /// fall below the protocol's minimum NIST security level.
+    pub fn check_algorithm(&self, algorithm: CryptoAlgorithm) -> Result<()> {
+        require!(self.is_allowed(algorithm), QuantumCustodyError::UnsupportedOperation);
+        require!(
+            algorithm.security_level() >= self.min_security_level,
+            QuantumCustodyError::InsufficientQuantumReadiness
+        );
+        Ok(())
+    }
 }
 
 #[account]
@@ -22,17 +70,1181 @@ pub struct QuantumVault {
     pub transaction_count: u64,
     pub compliance_status: ComplianceStatus,
     pub bump: u8,
+    pub nonce: u64,
+    pub signature_policy: SignaturePolicy,
+    pub vault_index: u64,
+    pub label: String,
+    pub rotation_policy: RotationPolicy,
+    /// Wallet that created the vault; with `vault_index` it forms the vault's
+    /// stable address, which survives ownership transfers.
+    pub creator: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    /// Bumped on every ownership change; session grants and queued
+    /// withdrawals made under an earlier owner can no longer be used.
+    pub owner_epoch: u32,
+    pub recovery: RecoveryConfig,
+    pub withdrawal_tiers: Vec<WithdrawalTier>,
+    /// Looser tiers waiting out the strongest current tier's delay before
+    /// taking effect.
+    pub pending_withdrawal_tiers: Option<PendingWithdrawalTiers>,
+    pub spending_limits: Vec<SpendingLimit>,
+    /// Looser limits waiting out `SpendingLimit::DELAY` before taking effect.
+    pub pending_spending_limits: Option<PendingSpendingLimits>,
+    /// Set once an `AddressBook` exists; withdrawals must then name an active entry.
+    pub has_address_book: bool,
+    /// Set once a `KeyHistory` exists; `close_vault` must then close it.
+    pub has_key_history: bool,
+    /// Set once an `EncryptionKeyRecord` exists; `close_vault` must then close it.
+    pub has_encryption_key: bool,
+    /// Mints whose associated token account the vault has opened. SPL
+    /// withdrawals go through these accounts only, and `close_vault` requires
+    /// every one of them closed.
+    pub token_mints: Vec<Pubkey>,
+    /// Blocks signing, rotation, withdrawals, ownership changes, recovery and
+    /// every configuration change; revoking sessions and cancelling queued
+    /// withdrawals remain possible.
+    pub frozen: bool,
+    /// Set by a PQ-signed unfreeze request; cleared by any new freeze.
+    pub unfreeze_at: Option<i64>,
+    /// Time of the last nonce-consuming operation or `heartbeat`.
+    pub last_activity: i64,
+    /// Nonce-consuming operations, heartbeats and recoveries so far. Claims
+    /// compare it rather than `last_activity`, so activity in the same second
+    /// a claim starts still objects to it.
+    pub activity_count: u64,
+    pub inheritance: Option<InheritanceConfig>,
 }
 
 impl QuantumVault {
-    pub const SPACE: usize = 32 + 1 + 4 + 2592 + 8 + 8 + 8 + 32 + 1;
+    pub const MAX_LABEL_LEN: usize = 32;
+    pub const MAX_TOKEN_MINTS: usize = 8;
+    /// Payload the vault key signs to authorize `close_vault`.
+    pub const CLOSE_STATEMENT: &'static [u8] = b"CLOSE_VAULT";
+    /// Payload the vault key signs to request `unfreeze_vault`.
+    pub const UNFREEZE_STATEMENT: &'static [u8] = b"UNFREEZE_VAULT";
+    pub const UNFREEZE_DELAY: i64 = 2 * 24 * 60 * 60;
+    pub const SPACE: usize = 32 + 1 + 4 + 2592 + 8 + 8 + 8 + 32 + 1 + 8 + 1 + 8 + 4 + Self::MAX_LABEL_LEN
+        + RotationPolicy::SPACE + 32 + 33 + 4 + RecoveryConfig::SPACE
+        + 4 + WithdrawalTier::SPACE * WithdrawalTier::MAX_TIERS + 1 + PendingWithdrawalTiers::SPACE
+        + 4 + SpendingLimit::SPACE * SpendingLimit::MAX_LIMITS + 1 + PendingSpendingLimits::SPACE + 1
+        + 1 + 1 + 4 + 32 * Self::MAX_TOKEN_MINTS + 1 + 9
+        + 8 + 8 + 1 + InheritanceConfig::SPACE;
+    
+    pub fn rotation_due_at(&self) -> i64 {
+        self.last_key_rotation.saturating_add(self.rotation_policy.period)
+    }
     
     pub fn is_key_rotation_required(&self, current_time: i64) -> bool {
-        const ROTATION_PERIOD: i64 = 90 * 24 * 60 * 60;
-        current_time - self.last_key_rotation > ROTATION_PERIOD
+        current_time > self.rotation_due_at()
+    }
+    
+    /// True within the grace window leading up to the rotation deadline.
+    pub fn is_key_rotation_due_soon(&self, current_time: i64) -> bool {
+        !self.is_key_rotation_required(current_time)
+            && current_time > self.rotation_due_at() - self.rotation_policy.grace_period
+    }
+    
+    /// Time lock for withdrawing `amount` of `mint` (`None` for SOL): the
+    /// delay of the highest tier the amount reaches, or zero below every tier.
+    pub fn withdrawal_delay(&self, mint: Option<Pubkey>, amount: u64) -> i64 {
+        WithdrawalTier::delay(&self.withdrawal_tiers, mint, amount)
+    }
+    
+    /// Promotes staged looser tiers once their delay has passed.
+    pub fn apply_pending_withdrawal_tiers(&mut self, now: i64) {
+        if let Some(pending) = self.pending_withdrawal_tiers.take() {
+            if now >= pending.effective_at {
+                self.withdrawal_tiers = pending.tiers;
+            } else {
+                self.pending_withdrawal_tiers = Some(pending);
+            }
+        }
+    }
+    
+    /// True if withdrawing `amount` of `mint` now would exceed any of the
+    /// vault's spending limits for that asset.
+    pub fn exceeds_spending_limits(&self, mint: Option<Pubkey>, amount: u64, now: i64) -> bool {
+        self.spending_limits
+            .iter()
+            .filter(|limit| limit.config.mint == mint)
+            .any(|limit| limit.spent_at(now).saturating_add(amount) > limit.config.max_amount)
+    }
+    
+    /// Adds `amount` of `mint` to every matching limit's usage. With `enforce`
+    /// set, fails instead if any limit would be exceeded; withdrawals queued
+    /// over the limits already waited out `SpendingLimit::DELAY`, so they are
+    /// only counted.
+    pub fn record_spend(&mut self, mint: Option<Pubkey>, amount: u64, now: i64, enforce: bool) -> Result<()> {
+        self.apply_pending_spending_limits(now);
+        
+        require!(
+            !enforce || !self.exceeds_spending_limits(mint, amount, now),
+            QuantumCustodyError::SpendingLimitExceeded
+        );
+        
+        for limit in self.spending_limits.iter_mut().filter(|limit| limit.config.mint == mint) {
+            limit.spent = limit.spent_at(now).saturating_add(amount);
+            limit.updated_at = now;
+        }
+        Ok(())
+    }
+    
+    /// Replaces the spending limits, carrying usage over to limits with the
+    /// same asset and period so a reconfiguration cannot reset them.
+    pub fn set_spending_limits(&mut self, configs: &[SpendingLimitConfig], now: i64) {
+        self.spending_limits = configs
+            .iter()
+            .map(|config| {
+                let spent = self.spending_limits
+                    .iter()
+                    .find(|limit| limit.config.mint == config.mint && limit.config.period == config.period)
+                    .map_or(0, |limit| limit.spent_at(now));
+                SpendingLimit { config: *config, spent, updated_at: now }
+            })
+            .collect();
+    }
+    
+    /// Promotes staged looser limits once their delay has passed.
+    pub fn apply_pending_spending_limits(&mut self, now: i64) {
+        if let Some(pending) = self.pending_spending_limits.take() {
+            if now >= pending.effective_at {
+                self.set_spending_limits(&pending.limits, now);
+            } else {
+                self.pending_spending_limits = Some(pending);
+            }
+        }
+    }
+    
+    /// Latest sign of life: a signed operation, heartbeat or key rotation.
+    pub fn last_active_at(&self) -> i64 {
+        self.last_activity.max(self.last_key_rotation)
+    }
+    
+    /// Time locks, spending limits and address books constrain withdrawals by
+    /// amount and destination, which arbitrary `execute` calls could bypass.
+    pub fn has_transfer_controls(&self) -> bool {
+        !self.withdrawal_tiers.is_empty() || !self.spending_limits.is_empty() || self.has_address_book
+    }
+    
+    /// Hands the vault to `owner`, retiring grants and queued withdrawals
+    /// authorized under the previous owner.
+    pub fn transfer_ownership(&mut self, owner: Pubkey) -> Result<()> {
+        self.owner = owner;
+        self.pending_owner = None;
+        self.owner_epoch = self.owner_epoch
+            .checked_add(1)
+            .ok_or(QuantumCustodyError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// The owner, any guardian and the protocol authority may freeze a vault.
+    pub fn can_freeze(&self, signer: &Pubkey, global_state: &GlobalState) -> bool {
+        *signer == self.owner
+            || *signer == global_state.authority
+            || self.recovery.guardian_index(signer).is_some()
+    }
+    
+    /// Checks a withdrawal of `amount` of `mint` to `destination` against the
+    /// vault's address book, which must be supplied once one exists.
+    pub fn check_destination(
+        &self,
+        address_book: Option<&Account<AddressBook>>,
+        mint: Option<Pubkey>,
+        destination: Pubkey,
+        amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        if !self.has_address_book {
+            return Ok(());
+        }
+        
+        address_book
+            .ok_or(QuantumCustodyError::MissingActionAccounts)?
+            .check(mint, destination, amount, now)
+    }
+    
+    /// Records that the vault opened its associated token account for `mint`.
+    pub fn open_token_account(&mut self, mint: Pubkey) -> Result<()> {
+        if self.token_mints.contains(&mint) {
+            return Ok(());
+        }
+        require!(
+            self.token_mints.len() < Self::MAX_TOKEN_MINTS,
+            QuantumCustodyError::TokenAccountLimitReached
+        );
+        self.token_mints.push(mint);
+        Ok(())
+    }
+    
+    /// Accepts `token_account` only as the vault's opened associated token
+    /// account for `mint`, the accounts `close_vault` knows to close.
+    pub fn check_token_account(&self, vault: &Pubkey, mint: &Pubkey, token_account: &Pubkey) -> Result<()> {
+        require!(self.token_mints.contains(mint), QuantumCustodyError::TokenAccountNotOpened);
+        require_keys_eq!(
+            *token_account,
+            get_associated_token_address(vault, mint),
+            QuantumCustodyError::TokenAccountNotOpened
+        );
+        Ok(())
+    }
+    
+    /// Accepts `nonce` only if it is the vault's next nonce and `expiry_slot`
+    /// has not passed, then advances the nonce so it can never be reused.
+    /// Every signed operation passes through here, so it also marks the vault
+    /// as active for the inheritance heartbeat.
+    pub fn consume_nonce(&mut self, nonce: u64, expiry_slot: u64, clock: &Clock) -> Result<()> {
+        require!(nonce >= self.nonce, QuantumCustodyError::NonceAlreadyUsed);
+        require!(nonce == self.nonce, QuantumCustodyError::InvalidNonce);
+        require!(clock.slot <= expiry_slot, QuantumCustodyError::SignatureExpired);
+        
+        self.nonce = self.nonce
+            .checked_add(1)
+            .ok_or(QuantumCustodyError::MathOverflow)?;
+        self.record_activity(clock.unix_timestamp);
+        
+        Ok(())
+    }
+    
+    /// Marks the vault as active, objecting to any inheritance claim started
+    /// before this point.
+    pub fn record_activity(&mut self, now: i64) {
+        self.last_activity = now;
+        self.activity_count = self.activity_count.wrapping_add(1);
     }
 }
 
+/// How long a vault key may be used before it must be rotated, and how long
+/// before that deadline signing starts emitting warnings. Both in seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RotationPolicy {
+    pub period: i64,
+    pub grace_period: i64,
+}
+
+impl RotationPolicy {
+    pub const SPACE: usize = 8 + 8;
+    pub const MIN_PERIOD: i64 = 7 * 24 * 60 * 60;
+    pub const MAX_PERIOD: i64 = 365 * 24 * 60 * 60;
+    
+    pub const DEFAULT: Self = Self {
+        period: 90 * 24 * 60 * 60,
+        grace_period: 7 * 24 * 60 * 60,
+    };
+    
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (Self::MIN_PERIOD..=Self::MAX_PERIOD).contains(&self.period)
+                && (0..self.period).contains(&self.grace_period),
+            QuantumCustodyError::InvalidRotationPolicy
+        );
+        Ok(())
+    }
+}
+
+/// Delegated key allowed to act for a vault within `scope`, so routine
+/// operations avoid the root PQ key. Created by `grant_session`.
+#[account]
+pub struct SessionGrant {
+    pub vault: Pubkey,
+    pub session_key: SessionKey,
+    pub scope: SessionScope,
+    /// Base units withdrawn under this grant so far, bounded by `scope.max_amount`.
+    pub spent: u64,
+    /// Vault's `owner_epoch` when granted; the grant lapses once it changes.
+    pub owner_epoch: u32,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl SessionGrant {
+    pub const BASE_SPACE: usize = 32 + SessionScope::SPACE + 8 + 4 + 8 + 1;
+    /// Roughly one day of slots; sessions are meant to be short-lived.
+    pub const MAX_DURATION_SLOTS: u64 = 216_000;
+    
+    pub fn space(session_key: &SessionKey) -> usize {
+        Self::BASE_SPACE + session_key.space()
+    }
+    
+    pub fn check_program(&self, program_id: &Pubkey, current_slot: u64) -> Result<()> {
+        require!(current_slot <= self.scope.expiry_slot, QuantumCustodyError::SessionExpired);
+        require!(
+            self.scope.allowed_programs.contains(program_id),
+            QuantumCustodyError::SessionScopeExceeded
+        );
+        Ok(())
+    }
+    
+    pub fn record_spend(&mut self, amount: u64) -> Result<()> {
+        self.spent = self.spent
+            .checked_add(amount)
+            .filter(|spent| *spent <= self.scope.max_amount)
+            .ok_or(QuantumCustodyError::SessionScopeExceeded)?;
+        Ok(())
+    }
+}
+
+/// Key a session grant delegates to. PQ session keys sign through a
+/// `VerificationSession` started with `start_key_verification`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum SessionKey {
+    Ed25519(Pubkey),
+    PostQuantum {
+        algorithm: CryptoAlgorithm,
+        public_key: Vec<u8>,
+    },
+}
+
+impl SessionKey {
+    pub fn space(&self) -> usize {
+        1 + match self {
+            SessionKey::Ed25519(_) => 32,
+            SessionKey::PostQuantum { public_key, .. } => 1 + 4 + public_key.len(),
+        }
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if let SessionKey::PostQuantum { algorithm, public_key } = self {
+            require!(
+                algorithm.is_verifiable(),
+                QuantumCustodyError::UnsupportedOperation
+            );
+            require!(
+                public_key.len() == algorithm.public_key_size(),
+                QuantumCustodyError::InvalidPublicKeySize
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Limits on a session grant: CPIs and withdrawals may only involve
+/// `allowed_programs` (the system program for SOL, the token program for
+/// SPL), `session_withdraw` withdrawals total at most `max_amount`, and the
+/// grant lapses after `expiry_slot`.
+///
+/// `max_amount` meters `session_withdraw` only. `session_execute` passes the
+/// vault as a signer to every program in `allowed_programs`, which may then
+/// move any of the vault's funds (for instance by calling the token program
+/// itself) without being charged against `max_amount`; only programs trusted
+/// with the whole vault belong there.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SessionScope {
+    pub allowed_programs: Vec<Pubkey>,
+    pub max_amount: u64,
+    pub expiry_slot: u64,
+}
+
+impl SessionScope {
+    pub const MAX_PROGRAMS: usize = 4;
+    pub const SPACE: usize = 4 + 32 * Self::MAX_PROGRAMS + 8 + 8;
+    
+    pub fn validate(&self, current_slot: u64) -> Result<()> {
+        require!(
+            !self.allowed_programs.is_empty()
+                && self.allowed_programs.len() <= Self::MAX_PROGRAMS
+                && !self.allowed_programs.contains(&crate::ID)
+                && self.expiry_slot > current_slot
+                && self.expiry_slot - current_slot <= SessionGrant::MAX_DURATION_SLOTS,
+            QuantumCustodyError::InvalidSessionGrant
+        );
+        Ok(())
+    }
+}
+
+/// Payload the root vault key signs to create a session grant.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SessionGrantStatement {
+    pub session_key: SessionKey,
+    pub scope: SessionScope,
+}
+
+/// Destinations a vault may withdraw to. Entries only become usable
+/// `COOLING_OFF` seconds after being added.
+#[account]
+pub struct AddressBook {
+    pub vault: Pubkey,
+    pub entries: Vec<AddressBookEntry>,
+    pub bump: u8,
+}
+
+impl AddressBook {
+    pub const MAX_ENTRIES: usize = 16;
+    pub const COOLING_OFF: i64 = 24 * 60 * 60;
+    pub const SPACE: usize = 32 + 4 + AddressBookEntry::SPACE * Self::MAX_ENTRIES + 1;
+    
+    pub fn find(&self, mint: Option<Pubkey>, destination: Pubkey) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.mint == mint && entry.destination == destination)
+    }
+    
+    pub fn check(&self, mint: Option<Pubkey>, destination: Pubkey, amount: u64, now: i64) -> Result<()> {
+        let entry = self.find(mint, destination)
+            .map(|index| &self.entries[index])
+            .ok_or(QuantumCustodyError::DestinationNotAllowed)?;
+        
+        require!(now >= entry.active_at, QuantumCustodyError::DestinationCoolingOff);
+        if let Some(cap) = entry.cap {
+            require!(amount <= cap, QuantumCustodyError::DestinationCapExceeded);
+        }
+        Ok(())
+    }
+}
+
+/// Approved `destination` for `mint` (`None` for SOL), optionally capping
+/// each withdrawal at `cap` base units. For SPL mints the destination is the
+/// receiving token account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct AddressBookEntry {
+    pub destination: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub cap: Option<u64>,
+    pub active_at: i64,
+}
+
+impl AddressBookEntry {
+    pub const SPACE: usize = 32 + 33 + 9 + 8;
+}
+
+/// Address book change signed by the vault key; its Borsh encoding is the
+/// `SigningDomain` payload.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum AddressBookChange {
+    Add {
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+        cap: Option<u64>,
+    },
+    Remove {
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+    },
+}
+
+/// At most `max_amount` base units of `mint` (`None` for SOL) may leave the
+/// vault per `period` seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SpendingLimitConfig {
+    pub mint: Option<Pubkey>,
+    pub period: i64,
+    pub max_amount: u64,
+}
+
+impl SpendingLimitConfig {
+    pub const SPACE: usize = 33 + 8 + 8;
+    
+    pub fn validate(configs: &[Self]) -> Result<()> {
+        require!(
+            configs.len() <= SpendingLimit::MAX_LIMITS,
+            QuantumCustodyError::InvalidSpendingLimits
+        );
+        
+        for (i, config) in configs.iter().enumerate() {
+            require!(
+                config.max_amount > 0
+                    && (SpendingLimit::MIN_PERIOD..=SpendingLimit::MAX_PERIOD).contains(&config.period)
+                    && !configs[..i]
+                        .iter()
+                        .any(|prev| prev.mint == config.mint && prev.period == config.period),
+                QuantumCustodyError::InvalidSpendingLimits
+            );
+        }
+        Ok(())
+    }
+    
+    /// True unless every current limit is kept with the same or a lower
+    /// maximum; such changes only take effect after `SpendingLimit::DELAY`.
+    pub fn loosens(current: &[SpendingLimit], proposed: &[Self]) -> bool {
+        !current.iter().all(|limit| {
+            proposed.iter().any(|config| {
+                config.mint == limit.config.mint
+                    && config.period == limit.config.period
+                    && config.max_amount <= limit.config.max_amount
+            })
+        })
+    }
+}
+
+/// A spending limit and its usage. Usage drains linearly at
+/// `max_amount / period`, approximating a rolling window without keeping
+/// per-withdrawal history.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SpendingLimit {
+    pub config: SpendingLimitConfig,
+    pub spent: u64,
+    pub updated_at: i64,
+}
+
+impl SpendingLimit {
+    pub const SPACE: usize = SpendingLimitConfig::SPACE + 8 + 8;
+    pub const MAX_LIMITS: usize = 8;
+    pub const MIN_PERIOD: i64 = 60 * 60;
+    pub const MAX_PERIOD: i64 = 30 * 24 * 60 * 60;
+    /// Wait before looser limits apply, and minimum lock on queued
+    /// withdrawals that would exceed the current limits.
+    pub const DELAY: i64 = 2 * 24 * 60 * 60;
+    
+    /// Usage at `now`. Over-limit queued withdrawals can leave usage above
+    /// `max_amount`, so draining is not capped at one period's worth.
+    pub fn spent_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.updated_at).max(0);
+        let released = self.config.max_amount as u128 * elapsed as u128 / self.config.period as u128;
+        self.spent.saturating_sub(released.min(u64::MAX as u128) as u64)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingSpendingLimits {
+    pub limits: Vec<SpendingLimitConfig>,
+    pub effective_at: i64,
+}
+
+impl PendingSpendingLimits {
+    pub const SPACE: usize = 4 + SpendingLimitConfig::SPACE * SpendingLimit::MAX_LIMITS + 8;
+}
+
+/// Withdrawals of `mint` (`None` for SOL) of at least `min_amount` base
+/// units must be queued and wait `delay` seconds before executing.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalTier {
+    pub mint: Option<Pubkey>,
+    pub min_amount: u64,
+    pub delay: i64,
+}
+
+impl WithdrawalTier {
+    pub const SPACE: usize = 33 + 8 + 8;
+    pub const MAX_TIERS: usize = 8;
+    pub const MAX_DELAY: i64 = 30 * 24 * 60 * 60;
+    
+    /// Tiers for one asset must rise in both amount and delay, so a larger
+    /// withdrawal is never unlocked sooner than a smaller one.
+    pub fn validate(tiers: &[Self]) -> Result<()> {
+        require!(tiers.len() <= Self::MAX_TIERS, QuantumCustodyError::InvalidWithdrawalTiers);
+        
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.min_amount > 0 && (1..=Self::MAX_DELAY).contains(&tier.delay),
+                QuantumCustodyError::InvalidWithdrawalTiers
+            );
+            require!(
+                tiers[..i]
+                    .iter()
+                    .filter(|prev| prev.mint == tier.mint)
+                    .all(|prev| prev.min_amount < tier.min_amount && prev.delay < tier.delay),
+                QuantumCustodyError::InvalidWithdrawalTiers
+            );
+        }
+        Ok(())
+    }
+    
+    /// Delay of the highest tier in `tiers` that `amount` of `mint` reaches,
+    /// or zero below every tier.
+    pub fn delay(tiers: &[Self], mint: Option<Pubkey>, amount: u64) -> i64 {
+        tiers
+            .iter()
+            .filter(|tier| tier.mint == mint && amount >= tier.min_amount)
+            .map(|tier| tier.delay)
+            .max()
+            .unwrap_or(0)
+    }
+    
+    /// True if `proposed` would release any withdrawal sooner than `current`.
+    /// Delays only step up at tier thresholds, so comparing there suffices.
+    pub fn loosens(current: &[Self], proposed: &[Self]) -> bool {
+        current
+            .iter()
+            .any(|tier| Self::delay(proposed, tier.mint, tier.min_amount) < tier.delay)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingWithdrawalTiers {
+    pub tiers: Vec<WithdrawalTier>,
+    pub effective_at: i64,
+}
+
+impl PendingWithdrawalTiers {
+    pub const SPACE: usize = 4 + WithdrawalTier::SPACE * WithdrawalTier::MAX_TIERS + 8;
+}
+
+/// A withdrawal authorized by the vault key but held until `unlock_at`.
+/// Anyone may execute it afterwards; the vault key may cancel it until then.
+#[account]
+pub struct PendingWithdrawal {
+    pub vault: Pubkey,
+    pub action: VaultAction,
+    pub payer: Pubkey,
+    pub unlock_at: i64,
+    pub created_at: i64,
+    /// Queued over the spending limits and so held for at least
+    /// `SpendingLimit::DELAY`; executing it only counts towards the limits.
+    pub over_limit: bool,
+    /// Vault's `owner_epoch` when queued; the withdrawal cannot execute once
+    /// it changes.
+    pub owner_epoch: u32,
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    pub const SPACE: usize = 32 + VaultAction::SPACE + 32 + 8 + 8 + 1 + 4 + 1;
+    
+    /// Payload the vault key signs to cancel this withdrawal.
+    pub fn cancel_statement(address: &Pubkey) -> Vec<u8> {
+        [&b"CANCEL_WITHDRAWAL"[..], address.as_ref()].concat()
+    }
+}
+
+/// Guardians able to replace a lost vault key. `threshold` of them must
+/// approve, after which the current key has `delay` seconds to cancel.
+/// An empty guardian list disables recovery.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub delay: i64,
+}
+
+impl RecoveryConfig {
+    pub const MAX_GUARDIANS: usize = 5;
+    pub const MIN_DELAY: i64 = 24 * 60 * 60;
+    pub const MAX_DELAY: i64 = 30 * 24 * 60 * 60;
+    pub const SPACE: usize = 4 + 32 * Self::MAX_GUARDIANS + 1 + 8;
+    
+    pub fn validate(&self) -> Result<()> {
+        if self.guardians.is_empty() {
+            return Ok(());
+        }
+        
+        require!(
+            self.guardians.len() <= Self::MAX_GUARDIANS
+                && self.guardians.iter().enumerate().all(|(i, g)| !self.guardians[..i].contains(g)),
+            QuantumCustodyError::InvalidMembers
+        );
+        require!(
+            self.threshold > 0 && self.threshold as usize <= self.guardians.len(),
+            QuantumCustodyError::InvalidThreshold
+        );
+        require!(
+            (Self::MIN_DELAY..=Self::MAX_DELAY).contains(&self.delay),
+            QuantumCustodyError::InvalidRecoveryDelay
+        );
+        Ok(())
+    }
+    
+    pub fn guardian_index(&self, guardian: &Pubkey) -> Option<usize> {
+        self.guardians.iter().position(|g| g == guardian)
+    }
+    
+    /// Fingerprint of the guardian set, so proposals go stale if it changes.
+    pub fn guardians_hash(&self) -> [u8; 32] {
+        let bytes: Vec<&[u8]> = self.guardians.iter().map(|g| g.as_ref()).collect();
+        hashv(&bytes).to_bytes()
+    }
+}
+
+/// Guardian proposal to replace a vault's PQ key with the key hashing to
+/// `new_key_hash`. Each guardian has one proposal slot per vault. `unlock_at`
+/// is set once the threshold is reached; past `expires_at` the proposal can
+/// no longer be approved or executed and anyone may close it.
+#[account]
+pub struct RecoveryProposal {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_key_hash: [u8; 32],
+    pub guardians_hash: [u8; 32],
+    pub approvals: u8,
+    pub approval_count: u8,
+    pub unlock_at: Option<i64>,
+    pub expires_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl RecoveryProposal {
+    pub const SPACE: usize = 32 + 32 + 32 + 32 + 1 + 1 + 9 + 8 + 8 + 1;
+    /// Time guardians have to reach the threshold.
+    pub const APPROVAL_WINDOW: i64 = 14 * 24 * 60 * 60;
+    /// Time left to execute once the timelock ends.
+    pub const EXECUTION_WINDOW: i64 = 14 * 24 * 60 * 60;
+    
+    /// Payload the current vault key signs to cancel this proposal.
+    pub fn cancel_statement(&self) -> Vec<u8> {
+        [&b"CANCEL_RECOVERY"[..], &self.new_key_hash].concat()
+    }
+    
+    /// Records `guardian_index`'s approval and starts the timelock once
+    /// `config.threshold` approvals are collected.
+    pub fn approve(&mut self, config: &RecoveryConfig, guardian_index: usize, now: i64) -> Result<()> {
+        require!(now < self.expires_at, QuantumCustodyError::RecoveryExpired);
+        require!(
+            self.approvals & (1 << guardian_index) == 0,
+            QuantumCustodyError::AlreadyApproved
+        );
+        
+        self.approvals |= 1 << guardian_index;
+        self.approval_count += 1;
+        
+        if self.unlock_at.is_none() && self.approval_count >= config.threshold {
+            let unlock_at = now.saturating_add(config.delay);
+            self.unlock_at = Some(unlock_at);
+            self.expires_at = unlock_at.saturating_add(Self::EXECUTION_WINDOW);
+        }
+        
+        Ok(())
+    }
+}
+
+/// Per-owner counter of created vaults; the next vault's index is `vault_count`.
+#[account]
+pub struct VaultRegistry {
+    pub owner: Pubkey,
+    pub vault_count: u64,
+    pub bump: u8,
+}
+
+impl VaultRegistry {
+    pub const SPACE: usize = 32 + 8 + 1;
+}
+
+/// Canonical statement covered by a vault's PQ signature. Binding the program,
+/// vault, nonce and expiry prevents a signature from being replayed elsewhere.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SigningDomain {
+    pub program_id: Pubkey,
+    pub vault: Pubkey,
+    pub nonce: u64,
+    pub expiry_slot: u64,
+    pub payload_hash: [u8; 32],
+}
+
+impl SigningDomain {
+    pub const TAG: &'static [u8] = b"SOLANA-PQC/v1";
+    
+    pub fn new(vault: Pubkey, nonce: u64, expiry_slot: u64, payload: &[u8]) -> Self {
+        Self {
+            program_id: crate::ID,
+            vault,
+            nonce,
+            expiry_slot,
+            payload_hash: hash(payload).to_bytes(),
+        }
+    }
+    
+    pub fn to_message(&self) -> Result<Vec<u8>> {
+        let mut message = Self::TAG.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+/// ML-KEM public key published by a vault so counterparties can encrypt to it.
+#[account]
+pub struct EncryptionKeyRecord {
+    pub vault: Pubkey,
+    pub algorithm: CryptoAlgorithm,
+    pub public_key: Vec<u8>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl EncryptionKeyRecord {
+    pub const SPACE: usize = 32 + 1 + 4 + 1568 + 8 + 1;
+}
+
+/// Payload signed by a vault to publish `key_hash` as its encryption key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EncryptionKeyStatement {
+    pub algorithm: CryptoAlgorithm,
+    pub key_hash: [u8; 32],
+}
+
+/// Payload signed by the current vault key to authorize replacing it. Wrapped
+/// in a `SigningDomain`, which adds the vault address and nonce.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RotationStatement {
+    pub old_key_hash: [u8; 32],
+    pub new_key_hash: [u8; 32],
+}
+
+impl RotationStatement {
+    pub fn new(old_public_key: &[u8], new_public_key: &[u8]) -> Self {
+        Self {
+            old_key_hash: hash(old_public_key).to_bytes(),
+            new_key_hash: hash(new_public_key).to_bytes(),
+        }
+    }
+}
+
+/// Bounded log of a vault's retired keys. Once `MAX_ENTRIES` are stored the
+/// oldest is overwritten at `head`; the current key is the vault's own
+/// `public_key`, active since `last_key_rotation`.
+#[account]
+pub struct KeyHistory {
+    pub vault: Pubkey,
+    /// Index the next entry is written to.
+    pub head: u32,
+    /// Entries ever recorded, including overwritten ones.
+    pub total_recorded: u64,
+    pub entries: Vec<KeyHistoryEntry>,
+    pub bump: u8,
+}
+
+impl KeyHistory {
+    pub const MAX_ENTRIES: usize = 32;
+    pub const SPACE: usize = 32 + 4 + 8 + 4 + KeyHistoryEntry::SPACE * Self::MAX_ENTRIES + 1;
+    
+    /// Records the vault's current key as retired at `now`. Must be called
+    /// before the vault's key fields are replaced.
+    pub fn record(&mut self, vault: &QuantumVault, reason: KeyRetirementReason, now: i64) {
+        let entry = KeyHistoryEntry {
+            key_hash: hash(&vault.public_key).to_bytes(),
+            algorithm: vault.algorithm,
+            activated_at: vault.last_key_rotation,
+            retired_at: now,
+            reason,
+        };
+        
+        let head = self.head as usize;
+        if head < self.entries.len() {
+            self.entries[head] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+        self.head = ((head + 1) % Self::MAX_ENTRIES) as u32;
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct KeyHistoryEntry {
+    pub key_hash: [u8; 32],
+    pub algorithm: CryptoAlgorithm,
+    pub activated_at: i64,
+    pub retired_at: i64,
+    pub reason: KeyRetirementReason,
+}
+
+impl KeyHistoryEntry {
+    pub const SPACE: usize = 32 + 1 + 8 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum KeyRetirementReason {
+    Rotation,
+    Migration,
+    Recovery,
+    Inheritance,
+}
+
+/// Dead-man switch: once the vault has been inactive for `inactivity_period`
+/// seconds, `beneficiary` may claim it with the PQ key hashing to
+/// `key_hash`. The claim completes `claim_delay` seconds later unless the
+/// vault shows any activity in between.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct InheritanceConfig {
+    pub beneficiary: Pubkey,
+    pub algorithm: CryptoAlgorithm,
+    pub key_hash: [u8; 32],
+    pub inactivity_period: i64,
+    pub claim_delay: i64,
+}
+
+impl InheritanceConfig {
+    pub const SPACE: usize = 32 + 1 + 32 + 8 + 8;
+    pub const MIN_INACTIVITY: i64 = 30 * 24 * 60 * 60;
+    pub const MAX_INACTIVITY: i64 = 5 * 365 * 24 * 60 * 60;
+    pub const MIN_CLAIM_DELAY: i64 = 24 * 60 * 60;
+    pub const MAX_CLAIM_DELAY: i64 = 90 * 24 * 60 * 60;
+    
+    pub fn validate(&self) -> Result<()> {
+        require!(self.algorithm.is_verifiable(), QuantumCustodyError::UnsupportedOperation);
+        require!(
+            (Self::MIN_INACTIVITY..=Self::MAX_INACTIVITY).contains(&self.inactivity_period)
+                && (Self::MIN_CLAIM_DELAY..=Self::MAX_CLAIM_DELAY).contains(&self.claim_delay),
+            QuantumCustodyError::InvalidInheritanceConfig
+        );
+        Ok(())
+    }
+}
+
+/// Beneficiary claim on an inactive vault, completable from `unlock_at`.
+#[account]
+pub struct InheritanceClaim {
+    pub vault: Pubkey,
+    pub beneficiary: Pubkey,
+    pub started_at: i64,
+    pub unlock_at: i64,
+    /// Vault's `activity_count` when the claim started; any change objects.
+    pub activity_count: u64,
+    pub bump: u8,
+}
+
+impl InheritanceClaim {
+    pub const SPACE: usize = 32 + 32 + 8 + 8 + 8 + 1;
+    /// Payload the beneficiary's PQ key signs to start a claim.
+    pub const CLAIM_STATEMENT: &'static [u8] = b"INHERITANCE_CLAIM";
+}
+
+/// Payload signed by the vault key to nominate `new_owner`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OwnerTransferStatement {
+    pub new_owner: Pubkey,
+}
+
+/// Payload signed by the current vault key to authorize switching the vault to
+/// `new_algorithm` with a new key. Wrapped in a `SigningDomain` like rotations.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrationStatement {
+    pub new_algorithm: CryptoAlgorithm,
+    pub old_key_hash: [u8; 32],
+    pub new_key_hash: [u8; 32],
+}
+
+impl MigrationStatement {
+    pub fn new(new_algorithm: CryptoAlgorithm, old_public_key: &[u8], new_public_key: &[u8]) -> Self {
+        Self {
+            new_algorithm,
+            old_key_hash: hash(old_public_key).to_bytes(),
+            new_key_hash: hash(new_public_key).to_bytes(),
+        }
+    }
+}
+
+/// M-of-N vault whose members are PQ keys. Only key hashes are stored so that
+/// large keys of mixed algorithms fit; approvers supply the full key in a buffer.
+#[account]
+pub struct MultisigVault {
+    pub creator: Pubkey,
+    pub multisig_id: u64,
+    pub members: Vec<MultisigMember>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl MultisigVault {
+    pub const MAX_MEMBERS: usize = 16;
+    pub const SPACE: usize = 32 + 8 + 4 + Self::MAX_MEMBERS * MultisigMember::SPACE + 1 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MultisigMember {
+    pub algorithm: CryptoAlgorithm,
+    pub key_hash: [u8; 32],
+}
+
+impl MultisigMember {
+    pub const SPACE: usize = 1 + 32;
+}
+
+/// Action awaiting approval by `threshold` members of a `MultisigVault`.
+/// Members sign the `SigningDomain` of the action with the proposal index as nonce.
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: VaultAction,
+    pub expiry_slot: u64,
+    pub approvals: u16,
+    pub approval_count: u8,
+    pub executed: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const SPACE: usize = 32 + 8 + 32 + VaultAction::SPACE + 8 + 2 + 1 + 1 + 8 + 1;
+    
+    pub fn signing_message(&self) -> Result<Vec<u8>> {
+        SigningDomain::new(self.multisig, self.index, self.expiry_slot, &self.action.try_to_vec()?)
+            .to_message()
+    }
+}
+
+/// Operations a vault's PQ key can authorize. The Borsh encoding of the action
+/// is the payload hashed into the `SigningDomain`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum VaultAction {
+    WithdrawSol {
+        destination: Pubkey,
+        amount: u64,
+    },
+    WithdrawSpl {
+        mint: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    Execute {
+        instructions_hash: [u8; 32],
+    },
+}
+
+impl VaultAction {
+    pub const SPACE: usize = 1 + 32 + 32 + 8;
+}
+
+/// Instruction invoked by `execute` with the vault PDA as signer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VaultInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<VaultAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VaultAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl From<&VaultInstruction> for Instruction {
+    fn from(instruction: &VaultInstruction) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        }
+    }
+}
+
+/// Staging account for payloads too large for a single transaction. The bytes
+/// are appended after the fixed header by `write_chunk` rather than stored in a
+/// `Vec`, so consumers can read them without copying onto the heap.
+#[account]
+pub struct SignatureBuffer {
+    pub authority: Pubkey,
+    pub total_len: u32,
+    pub written: u32,
+    pub finalized: bool,
+    pub bump: u8,
+}
+
+impl SignatureBuffer {
+    pub const SPACE: usize = 32 + 4 + 4 + 1 + 1;
+    pub const HEADER_SIZE: usize = 8 + Self::SPACE;
+    pub const MAX_LEN: usize = 17088;
+    
+    pub fn payload<'a, 'info>(buffer: &'a Account<'info, SignatureBuffer>) -> Result<Ref<'a, [u8]>> {
+        let end = Self::HEADER_SIZE + buffer.written as usize;
+        let info: &AccountInfo<'info> = buffer.as_ref();
+        let data = info.try_borrow_data()?;
+        Ok(Ref::map(data, |data| &data[Self::HEADER_SIZE..end]))
+    }
+}
+
+/// Tracks a post-quantum signature verification spread over several
+/// `continue_verification` instructions. Neither Dilithium nor SPHINCS+ fits
+/// the compute budget of a single transaction.
+#[account]
+pub struct VerificationSession {
+    pub vault: Pubkey,
+    pub signature_buffer: Pubkey,
+    pub algorithm: CryptoAlgorithm,
+    pub public_key: Vec<u8>,
+    pub message_hash: [u8; 32],
+    pub next_step: u32,
+    pub total_steps: u32,
+    pub verified: bool,
+    pub progress: VerificationProgress,
+    /// Authority of the signature buffer, who paid for the session and may
+    /// close it with `close_session`.
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+impl VerificationSession {
+    pub const SPACE: usize = 32 + 32 + 1 + 4 + 2592 + 32 + 4 + 4 + 1 + VerificationProgress::SPACE + 32 + 1;
+    
+    pub fn begin(
+        &mut self,
+        vault: Pubkey,
+        signature_buffer: &Account<SignatureBuffer>,
+        algorithm: CryptoAlgorithm,
+        public_key: &[u8],
+        message: &[u8],
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            message.len() <= 10_240,
+            QuantumCustodyError::MessageTooLarge
+        );
+        
+        require!(
+            public_key.len() == algorithm.public_key_size(),
+            QuantumCustodyError::InvalidPublicKeySize
+        );
+        
+        require!(algorithm.is_verifiable(), QuantumCustodyError::UnsupportedOperation);
+        
+        let signature = SignatureBuffer::payload(signature_buffer)?;
+        require!(
+            signature.len() == algorithm.signature_size(),
+            QuantumCustodyError::InvalidSignatureSize
+        );
+        
+        let (total_steps, progress) = if let Some(params) = crypto::dilithium_params(algorithm) {
+            let progress = dilithium::start(params, public_key, message, &signature)
+                .ok_or(QuantumCustodyError::SignatureVerificationFailed)?;
+            (params.total_steps(), VerificationProgress::Dilithium(progress))
+        } else if let Some(params) = crypto::sphincs_params(algorithm) {
+            let progress = sphincs::start(params, public_key, message, &signature);
+            (params.total_steps(), VerificationProgress::Sphincs(progress))
+        } else {
+            return err!(QuantumCustodyError::UnsupportedOperation);
+        };
+        
+        self.vault = vault;
+        self.signature_buffer = signature_buffer.key();
+        self.algorithm = algorithm;
+        self.public_key = public_key.to_vec();
+        self.message_hash = hash(message).to_bytes();
+        self.next_step = 0;
+        self.total_steps = total_steps;
+        self.verified = false;
+        self.progress = progress;
+        self.payer = signature_buffer.authority;
+        self.bump = bump;
+        
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum VerificationProgress {
+    Dilithium(dilithium::Progress),
+    Sphincs(sphincs::Progress),
+}
+
+impl VerificationProgress {
+    pub const SPACE: usize = 1 + if dilithium::Progress::SPACE > sphincs::Progress::SPACE {
+        dilithium::Progress::SPACE
+    } else {
+        sphincs::Progress::SPACE
+    };
+}
+
+/// Which signatures a vault requires over its canonical messages. The classical
+/// leg is an Ed25519 signature by the vault owner, checked through the Ed25519
+/// precompile; the PQ leg is a signature by the vault's `public_key`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SignaturePolicy {
+    PqOnly,
+    ClassicalOnly,
+    HybridBoth,
+    HybridEither,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum CryptoAlgorithm {
     Dilithium2,
@@ -48,6 +1260,22 @@ pub enum CryptoAlgorithm {
 }
 
 impl CryptoAlgorithm {
+    pub fn from_u8(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(CryptoAlgorithm::Dilithium2),
+            1 => Ok(CryptoAlgorithm::Dilithium3),
+            2 => Ok(CryptoAlgorithm::Dilithium5),
+            3 => Ok(CryptoAlgorithm::SphincsSha2128s),
+            4 => Ok(CryptoAlgorithm::SphincsSha2128f),
+            5 => Ok(CryptoAlgorithm::SphincsShake128s),
+            6 => Ok(CryptoAlgorithm::SphincsShake128f),
+            7 => Ok(CryptoAlgorithm::Kyber512),
+            8 => Ok(CryptoAlgorithm::Kyber768),
+            9 => Ok(CryptoAlgorithm::Kyber1024),
+            _ => Err(QuantumCustodyError::InvalidAlgorithm.into()),
+        }
+    }
+    
     pub fn public_key_size(&self) -> usize {
         match self {
             CryptoAlgorithm::Dilithium2 => 1312,
@@ -66,8 +1294,8 @@ impl CryptoAlgorithm {
     pub fn signature_size(&self) -> usize {
         match self {
             CryptoAlgorithm::Dilithium2 => 2420,
-            CryptoAlgorithm::Dilithium3 => 3293,
-            CryptoAlgorithm::Dilithium5 => 4595,
+            CryptoAlgorithm::Dilithium3 => 3309,
+            CryptoAlgorithm::Dilithium5 => 4627,
             CryptoAlgorithm::SphincsSha2128s => 7856,
             CryptoAlgorithm::SphincsSha2128f => 17088,
             CryptoAlgorithm::SphincsShake128s => 7856,
@@ -76,6 +1304,22 @@ impl CryptoAlgorithm {
         }
     }
     
+    /// ML-KEM (Kyber) keys can only encrypt; they can never authorize a vault.
+    pub fn is_kem(&self) -> bool {
+        matches!(
+            self,
+            CryptoAlgorithm::Kyber512 | CryptoAlgorithm::Kyber768 | CryptoAlgorithm::Kyber1024
+        )
+    }
+    
+    /// SPHINCS+-SHAKE hashes in BPF rather than through a syscall, putting one
+    /// verification at 61M-172M compute units: far too many transactions for
+    /// such a key ever to authorize anything.
+    pub fn is_verifiable(&self) -> bool {
+        !self.is_kem()
+            && !matches!(self, CryptoAlgorithm::SphincsShake128s | CryptoAlgorithm::SphincsShake128f)
+    }
+    
     pub fn security_level(&self) -> u8 {
         match self {
             CryptoAlgorithm::Dilithium2 | 
@@ -94,7 +1338,7 @@ impl CryptoAlgorithm {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ComplianceStatus {
     pub nist_fips_203_compliant: bool,
     pub nist_fips_204_compliant: bool,
@@ -103,14 +1347,386 @@ pub struct ComplianceStatus {
     pub quantum_readiness_score: u8,
 }
 
-impl Default for ComplianceStatus {
-    fn default() -> Self {
-        Self {
-            nist_fips_203_compliant: false,
-            nist_fips_204_compliant: false,
-            nist_fips_205_compliant: false,
-            last_audit: 0,
-            quantum_readiness_score: 0,
+impl ComplianceStatus {
+    /// Resets the FIPS flags and records the audit time after a key change.
+    /// The verifiers implement the round-3 Dilithium and SPHINCS+ submissions,
+    /// not FIPS 204 ML-DSA or FIPS 205 SLH-DSA, so no vault key meets a FIPS
+    /// standard and every flag stays clear.
+    pub fn record_audit(&mut self, timestamp: i64) {
+        self.nist_fips_203_compliant = false;
+        self.nist_fips_204_compliant = false;
+        self.nist_fips_205_compliant = false;
+        self.last_audit = timestamp;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60;
+
+    fn vault() -> QuantumVault {
+        QuantumVault {
+            owner: Pubkey::new_unique(),
+            algorithm: CryptoAlgorithm::Dilithium3,
+            public_key: vec![0; CryptoAlgorithm::Dilithium3.public_key_size()],
+            created_at: 0,
+            last_key_rotation: 0,
+            transaction_count: 0,
+            compliance_status: ComplianceStatus::default(),
+            bump: 255,
+            nonce: 0,
+            signature_policy: SignaturePolicy::PqOnly,
+            vault_index: 0,
+            label: String::new(),
+            rotation_policy: RotationPolicy::DEFAULT,
+            creator: Pubkey::new_unique(),
+            pending_owner: None,
+            owner_epoch: 0,
+            recovery: RecoveryConfig::default(),
+            withdrawal_tiers: Vec::new(),
+            pending_withdrawal_tiers: None,
+            spending_limits: Vec::new(),
+            pending_spending_limits: None,
+            has_address_book: false,
+            has_key_history: false,
+            has_encryption_key: false,
+            token_mints: Vec::new(),
+            frozen: false,
+            unfreeze_at: None,
+            last_activity: 0,
+            activity_count: 0,
+            inheritance: None,
+        }
+    }
+
+    fn limit(max_amount: u64, period: i64) -> SpendingLimitConfig {
+        SpendingLimitConfig { mint: None, period, max_amount }
+    }
+
+    fn usage(config: SpendingLimitConfig, spent: u64, updated_at: i64) -> SpendingLimit {
+        SpendingLimit { config, spent, updated_at }
+    }
+
+    #[test]
+    fn test_spending_limit_drains_linearly() {
+        let limit = usage(limit(1_000, HOUR), 600, 0);
+        assert_eq!(limit.spent_at(0), 600);
+        assert_eq!(limit.spent_at(HOUR / 4), 350);
+        assert_eq!(limit.spent_at(HOUR / 2), 100);
+        assert_eq!(limit.spent_at(HOUR * 6 / 10), 0);
+    }
+
+    #[test]
+    fn test_spending_limit_drain_is_clamped() {
+        let limit = usage(limit(1_000, HOUR), 1_000, 10 * HOUR);
+        assert_eq!(limit.spent_at(11 * HOUR), 0);
+        assert_eq!(limit.spent_at(i64::MAX), 0);
+        // A clock behind the last update releases nothing
+        assert_eq!(limit.spent_at(9 * HOUR), 1_000);
+        assert_eq!(limit.spent_at(i64::MIN), 1_000);
+    }
+
+    #[test]
+    fn test_spending_limit_drains_usage_above_maximum() {
+        // As left by over-limit queued withdrawals
+        let limit = usage(limit(1_000, HOUR), 5_000, 0);
+        assert_eq!(limit.spent_at(HOUR), 4_000);
+        assert_eq!(limit.spent_at(4 * HOUR), 1_000);
+        assert_eq!(limit.spent_at(5 * HOUR), 0);
+    }
+
+    #[test]
+    fn test_reconfiguration_carries_usage_over() {
+        let mut vault = vault();
+        vault.spending_limits = vec![usage(limit(1_000, HOUR), 800, 0)];
+
+        vault.set_spending_limits(&[limit(500, HOUR), limit(500, 2 * HOUR)], HOUR / 2);
+        assert_eq!(vault.spending_limits[0].spent, 300);
+        assert_eq!(vault.spending_limits[0].updated_at, HOUR / 2);
+        assert_eq!(vault.spending_limits[1].spent, 0);
+
+        let other_mint = SpendingLimitConfig { mint: Some(Pubkey::new_unique()), ..limit(500, HOUR) };
+        vault.set_spending_limits(&[other_mint], HOUR / 2);
+        assert_eq!(vault.spending_limits[0].spent, 0);
+    }
+
+    #[test]
+    fn test_loosens() {
+        let current = [usage(limit(1_000, HOUR), 0, 0), usage(limit(5_000, 24 * HOUR), 0, 0)];
+
+        // Removing a limit
+        assert!(SpendingLimitConfig::loosens(&current, &[limit(1_000, HOUR)]));
+        assert!(SpendingLimitConfig::loosens(&current, &[]));
+        // Raising a limit, or stretching its period
+        assert!(SpendingLimitConfig::loosens(&current, &[limit(1_001, HOUR), limit(5_000, 24 * HOUR)]));
+        assert!(SpendingLimitConfig::loosens(&current, &[limit(1_000, 2 * HOUR), limit(5_000, 24 * HOUR)]));
+        // Keeping, lowering or adding limits
+        assert!(!SpendingLimitConfig::loosens(&current, &[limit(1_000, HOUR), limit(5_000, 24 * HOUR)]));
+        assert!(!SpendingLimitConfig::loosens(&current, &[limit(999, HOUR), limit(1, 24 * HOUR)]));
+        assert!(!SpendingLimitConfig::loosens(
+            &current,
+            &[limit(1_000, HOUR), limit(5_000, 24 * HOUR), limit(100, 2 * HOUR)]
+        ));
+        assert!(!SpendingLimitConfig::loosens(&[], &[limit(1_000, HOUR)]));
+    }
+
+    #[test]
+    fn test_record_spend_enforces_limits() {
+        let mut vault = vault();
+        vault.set_spending_limits(&[limit(1_000, HOUR)], 0);
+
+        vault.record_spend(None, 700, 0, true).unwrap();
+        assert_eq!(
+            vault.record_spend(None, 301, 0, true).unwrap_err(),
+            QuantumCustodyError::SpendingLimitExceeded.into()
+        );
+        assert_eq!(vault.spending_limits[0].spent, 700);
+        vault.record_spend(None, 300, 0, true).unwrap();
+
+        // Other assets are not limited
+        vault.record_spend(Some(Pubkey::new_unique()), 10_000, 0, true).unwrap();
+        assert_eq!(vault.spending_limits[0].spent, 1_000);
+    }
+
+    #[test]
+    fn test_record_spend_counts_over_limit_withdrawals() {
+        let mut vault = vault();
+        vault.set_spending_limits(&[limit(1_000, HOUR)], 0);
+
+        vault.record_spend(None, 2_500, 0, false).unwrap();
+        assert_eq!(vault.spending_limits[0].spent, 2_500);
+        assert!(vault.exceeds_spending_limits(None, 1, HOUR));
+        assert!(!vault.exceeds_spending_limits(None, 1_000, 3 * HOUR));
+    }
+
+    #[test]
+    fn test_record_spend_applies_due_pending_limits() {
+        let mut vault = vault();
+        vault.set_spending_limits(&[limit(1_000, HOUR)], 0);
+        vault.pending_spending_limits = Some(PendingSpendingLimits {
+            limits: vec![limit(10_000, HOUR)],
+            effective_at: SpendingLimit::DELAY,
+        });
+
+        assert!(vault.record_spend(None, 5_000, SpendingLimit::DELAY - 1, true).is_err());
+        vault.record_spend(None, 5_000, SpendingLimit::DELAY, true).unwrap();
+        assert!(vault.pending_spending_limits.is_none());
+        assert_eq!(vault.spending_limits[0].config.max_amount, 10_000);
+    }
+
+    fn tier(mint: Option<Pubkey>, min_amount: u64, delay: i64) -> WithdrawalTier {
+        WithdrawalTier { mint, min_amount, delay }
+    }
+
+    #[test]
+    fn test_withdrawal_tiers_must_rise() {
+        let mint = Some(Pubkey::new_unique());
+        WithdrawalTier::validate(&[]).unwrap();
+        WithdrawalTier::validate(&[tier(None, 100, HOUR), tier(mint, 1, 2 * HOUR), tier(None, 1_000, 24 * HOUR)])
+            .unwrap();
+
+        let rejected = [
+            vec![tier(None, 0, HOUR)],
+            vec![tier(None, 100, 0)],
+            vec![tier(None, 100, WithdrawalTier::MAX_DELAY + 1)],
+            // Larger amounts must wait longer, and amounts must be distinct
+            vec![tier(None, 100, HOUR), tier(None, 1_000, HOUR)],
+            vec![tier(None, 1_000, HOUR), tier(None, 100, 2 * HOUR)],
+            vec![tier(None, 100, HOUR), tier(None, 100, 2 * HOUR)],
+            vec![tier(None, 100, HOUR); WithdrawalTier::MAX_TIERS + 1],
+        ];
+        for tiers in rejected {
+            assert_eq!(
+                WithdrawalTier::validate(&tiers).unwrap_err(),
+                QuantumCustodyError::InvalidWithdrawalTiers.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_withdrawal_delay_uses_highest_tier_reached() {
+        let mint = Some(Pubkey::new_unique());
+        let mut vault = vault();
+        vault.withdrawal_tiers = vec![tier(None, 100, HOUR), tier(None, 1_000, 24 * HOUR), tier(mint, 10, 2 * HOUR)];
+
+        assert_eq!(vault.withdrawal_delay(None, 99), 0);
+        assert_eq!(vault.withdrawal_delay(None, 100), HOUR);
+        assert_eq!(vault.withdrawal_delay(None, 999), HOUR);
+        assert_eq!(vault.withdrawal_delay(None, u64::MAX), 24 * HOUR);
+        assert_eq!(vault.withdrawal_delay(mint, 10), 2 * HOUR);
+        assert_eq!(vault.withdrawal_delay(Some(Pubkey::new_unique()), u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_withdrawal_tiers_loosen() {
+        let current = [tier(None, 100, HOUR), tier(None, 1_000, 24 * HOUR)];
+
+        assert!(WithdrawalTier::loosens(&current, &[]));
+        assert!(WithdrawalTier::loosens(&current, &[tier(None, 100, HOUR)]));
+        assert!(WithdrawalTier::loosens(&current, &[tier(None, 101, HOUR), tier(None, 1_000, 24 * HOUR)]));
+        assert!(WithdrawalTier::loosens(&current, &[tier(None, 100, HOUR), tier(None, 1_000, 2 * HOUR)]));
+
+        assert!(!WithdrawalTier::loosens(&current, &current));
+        assert!(!WithdrawalTier::loosens(&current, &[tier(None, 50, HOUR), tier(None, 1_000, 48 * HOUR)]));
+        assert!(!WithdrawalTier::loosens(&current, &[tier(None, 1, 24 * HOUR)]));
+        assert!(!WithdrawalTier::loosens(&[], &current));
+    }
+
+    #[test]
+    fn test_pending_withdrawal_tiers_apply_when_due() {
+        let mut vault = vault();
+        vault.withdrawal_tiers = vec![tier(None, 100, HOUR)];
+        vault.pending_withdrawal_tiers = Some(PendingWithdrawalTiers { tiers: Vec::new(), effective_at: HOUR });
+
+        vault.apply_pending_withdrawal_tiers(HOUR - 1);
+        assert_eq!(vault.withdrawal_delay(None, 100), HOUR);
+        assert!(vault.pending_withdrawal_tiers.is_some());
+
+        vault.apply_pending_withdrawal_tiers(HOUR);
+        assert_eq!(vault.withdrawal_delay(None, 100), 0);
+        assert!(vault.pending_withdrawal_tiers.is_none());
+    }
+
+    fn entry(destination: Pubkey, mint: Option<Pubkey>, cap: Option<u64>, active_at: i64) -> AddressBookEntry {
+        AddressBookEntry { destination, mint, cap, active_at }
+    }
+
+    #[test]
+    fn test_address_book_cooling_off() {
+        let destination = Pubkey::new_unique();
+        let book = AddressBook {
+            vault: Pubkey::new_unique(),
+            entries: vec![entry(destination, None, None, AddressBook::COOLING_OFF)],
+            bump: 255,
+        };
+
+        assert_eq!(
+            book.check(None, destination, 1, AddressBook::COOLING_OFF - 1).unwrap_err(),
+            QuantumCustodyError::DestinationCoolingOff.into()
+        );
+        book.check(None, destination, u64::MAX, AddressBook::COOLING_OFF).unwrap();
+    }
+
+    #[test]
+    fn test_address_book_cap_and_matching() {
+        let destination = Pubkey::new_unique();
+        let mint = Some(Pubkey::new_unique());
+        let book = AddressBook {
+            vault: Pubkey::new_unique(),
+            entries: vec![entry(destination, mint, Some(500), 0)],
+            bump: 255,
+        };
+
+        book.check(mint, destination, 500, 0).unwrap();
+        assert_eq!(
+            book.check(mint, destination, 501, 0).unwrap_err(),
+            QuantumCustodyError::DestinationCapExceeded.into()
+        );
+        // Entries are per asset and destination
+        for (mint, destination) in [(None, destination), (mint, Pubkey::new_unique())] {
+            assert_eq!(
+                book.check(mint, destination, 1, 0).unwrap_err(),
+                QuantumCustodyError::DestinationNotAllowed.into()
+            );
+        }
+    }
+
+    fn recovery(threshold: u8) -> (RecoveryConfig, RecoveryProposal) {
+        let config = RecoveryConfig {
+            guardians: (0..3).map(|_| Pubkey::new_unique()).collect(),
+            threshold,
+            delay: 24 * HOUR,
+        };
+        let proposal = RecoveryProposal {
+            vault: Pubkey::new_unique(),
+            proposer: config.guardians[0],
+            new_key_hash: [1; 32],
+            guardians_hash: config.guardians_hash(),
+            approvals: 0,
+            approval_count: 0,
+            unlock_at: None,
+            expires_at: RecoveryProposal::APPROVAL_WINDOW,
+            created_at: 0,
+            bump: 255,
+        };
+        (config, proposal)
+    }
+
+    #[test]
+    fn test_recovery_rejects_duplicate_approval() {
+        let (config, mut proposal) = recovery(2);
+
+        proposal.approve(&config, 1, 0).unwrap();
+        assert_eq!(
+            proposal.approve(&config, 1, 1).unwrap_err(),
+            QuantumCustodyError::AlreadyApproved.into()
+        );
+        assert_eq!(proposal.approval_count, 1);
+        assert_eq!(proposal.unlock_at, None);
+    }
+
+    #[test]
+    fn test_recovery_threshold_starts_timelock() {
+        let (config, mut proposal) = recovery(2);
+
+        proposal.approve(&config, 0, HOUR).unwrap();
+        assert_eq!(proposal.unlock_at, None);
+        assert_eq!(proposal.expires_at, RecoveryProposal::APPROVAL_WINDOW);
+
+        proposal.approve(&config, 2, 2 * HOUR).unwrap();
+        let unlock_at = 2 * HOUR + config.delay;
+        assert_eq!(proposal.unlock_at, Some(unlock_at));
+        assert_eq!(proposal.expires_at, unlock_at + RecoveryProposal::EXECUTION_WINDOW);
+
+        // Later approvals do not push the timelock back
+        proposal.approve(&config, 1, 3 * HOUR).unwrap();
+        assert_eq!(proposal.unlock_at, Some(unlock_at));
+        assert_eq!(proposal.approval_count, 3);
+    }
+
+    #[test]
+    fn test_recovery_approval_expires() {
+        let (config, mut proposal) = recovery(2);
+
+        proposal.approve(&config, 0, RecoveryProposal::APPROVAL_WINDOW - 1).unwrap();
+        assert_eq!(
+            proposal.approve(&config, 1, RecoveryProposal::APPROVAL_WINDOW).unwrap_err(),
+            QuantumCustodyError::RecoveryExpired.into()
+        );
+        assert_eq!(proposal.unlock_at, None);
+    }
+
+    #[test]
+    fn test_key_history_wraps_after_max_entries() {
+        let mut vault = vault();
+        let mut history = KeyHistory {
+            vault: Pubkey::new_unique(),
+            head: 0,
+            total_recorded: 0,
+            entries: Vec::new(),
+            bump: 255,
+        };
+        let records = KeyHistory::MAX_ENTRIES as i64 + 3;
+
+        for i in 0..records {
+            vault.public_key = i.to_le_bytes().to_vec();
+            vault.last_key_rotation = i;
+            history.record(&vault, KeyRetirementReason::Rotation, i + 1);
+
+            let recorded = i as usize + 1;
+            assert_eq!(history.head as usize, recorded % KeyHistory::MAX_ENTRIES);
+            assert_eq!(history.entries.len(), recorded.min(KeyHistory::MAX_ENTRIES));
+        }
+        assert_eq!(history.total_recorded, records as u64);
+
+        // The three newest entries overwrote the three oldest
+        for (slot, i) in [(0, 32_i64), (1, 33), (2, 34), (3, 3), (31, 31)] {
+            let entry = &history.entries[slot];
+            assert_eq!(entry.key_hash, hash(&i.to_le_bytes()).to_bytes());
+            assert_eq!(entry.activated_at, i);
+            assert_eq!(entry.retired_at, i + 1);
         }
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::{Result, SdkError, crypto::{Keypair, Algorithm}};
 use pqcrypto_dilithium::{dilithium2, dilithium3, dilithium5};
-use pqcrypto_traits::sign::{PublicKey, SecretKey, SignedMessage};
+use pqcrypto_traits::sign::{DetachedSignature, PublicKey, SecretKey};
 
 pub fn generate_dilithium2_keypair() -> Result<Keypair> {
     let (pk, sk) = dilithium2::keypair();
@@ -35,24 +35,54 @@ pub fn sign_dilithium2(message: &[u8], secret_key: &[u8]) -> Result<Vec<u8>> {
     let sk = dilithium2::SecretKey::from_bytes(secret_key)
         .map_err(|e| SdkError::CryptoError(format!("Invalid secret key: {:?}", e)))?;
     
-    let signed_msg = dilithium2::sign(message, &sk);
-    Ok(signed_msg.as_bytes().to_vec())
+    let signature = dilithium2::detached_sign(message, &sk);
+    Ok(signature.as_bytes().to_vec())
+}
+
+pub fn verify_dilithium2(message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<()> {
+    let pk = dilithium2::PublicKey::from_bytes(public_key)
+        .map_err(|_| SdkError::InvalidPublicKeySize)?;
+    let sig = dilithium2::DetachedSignature::from_bytes(signature)
+        .map_err(|_| SdkError::InvalidSignatureSize)?;
+    
+    dilithium2::verify_detached_signature(&sig, message, &pk)
+        .map_err(|_| SdkError::VerificationFailed)
 }
 
 pub fn sign_dilithium3(message: &[u8], secret_key: &[u8]) -> Result<Vec<u8>> {
     let sk = dilithium3::SecretKey::from_bytes(secret_key)
         .map_err(|e| SdkError::CryptoError(format!("Invalid secret key: {:?}", e)))?;
     
-    let signed_msg = dilithium3::sign(message, &sk);
-    Ok(signed_msg.as_bytes().to_vec())
+    let signature = dilithium3::detached_sign(message, &sk);
+    Ok(signature.as_bytes().to_vec())
+}
+
+pub fn verify_dilithium3(message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<()> {
+    let pk = dilithium3::PublicKey::from_bytes(public_key)
+        .map_err(|_| SdkError::InvalidPublicKeySize)?;
+    let sig = dilithium3::DetachedSignature::from_bytes(signature)
+        .map_err(|_| SdkError::InvalidSignatureSize)?;
+    
+    dilithium3::verify_detached_signature(&sig, message, &pk)
+        .map_err(|_| SdkError::VerificationFailed)
 }
 
 pub fn sign_dilithium5(message: &[u8], secret_key: &[u8]) -> Result<Vec<u8>> {
     let sk = dilithium5::SecretKey::from_bytes(secret_key)
         .map_err(|e| SdkError::CryptoError(format!("Invalid secret key: {:?}", e)))?;
     
-    let signed_msg = dilithium5::sign(message, &sk);
-    Ok(signed_msg.as_bytes().to_vec())
+    let signature = dilithium5::detached_sign(message, &sk);
+    Ok(signature.as_bytes().to_vec())
+}
+
+pub fn verify_dilithium5(message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<()> {
+    let pk = dilithium5::PublicKey::from_bytes(public_key)
+        .map_err(|_| SdkError::InvalidPublicKeySize)?;
+    let sig = dilithium5::DetachedSignature::from_bytes(signature)
+        .map_err(|_| SdkError::InvalidSignatureSize)?;
+    
+    dilithium5::verify_detached_signature(&sig, message, &pk)
+        .map_err(|_| SdkError::VerificationFailed)
 }
 
 #[cfg(test)]
@@ -64,6 +94,8 @@ mod tests {
         let keypair = generate_dilithium3_keypair().unwrap();
         let message = b"Test message for Dilithium3";
         let signature = sign_dilithium3(message, &keypair.secret_key).unwrap();
-        assert!(signature.len() > 0);
+        assert_eq!(signature.len(), 3309);
+        assert!(verify_dilithium3(message, &signature, &keypair.public_key).is_ok());
+        assert!(verify_dilithium3(b"Tampered message", &signature, &keypair.public_key).is_err());
     }
 }
@@ -95,3 +95,12 @@ impl Keypair {
         }
     }
 }
+
+pub fn verify(algorithm: Algorithm, message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<()> {
+    match algorithm {
+        Algorithm::Dilithium2 => dilithium::verify_dilithium2(message, signature, public_key),
+        Algorithm::Dilithium3 => dilithium::verify_dilithium3(message, signature, public_key),
+        Algorithm::Dilithium5 => dilithium::verify_dilithium5(message, signature, public_key),
+        _ => Err(SdkError::CryptoError("Algorithm does not support verification".to_string())),
+    }
+}
@@ -1,24 +1,59 @@
 //! Quantum-safe transaction signing
 
-use crate::{Result, crypto::Keypair};
+use crate::{Result, crypto::{Algorithm, Keypair}};
 use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    ed25519_program,
+    hash::hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair as Ed25519Keypair, Signature, Signer},
+};
+
+/// Prefix of every vault-signed message, matching the program's `SigningDomain`.
+pub const SIGNING_DOMAIN_TAG: &[u8] = b"SOLANA-PQC/v1";
+
+/// Payload for `close_vault`, matching the program's `QuantumVault::CLOSE_STATEMENT`.
+pub const CLOSE_VAULT_PAYLOAD: &[u8] = b"CLOSE_VAULT";
+
+/// Payload the beneficiary's PQ key signs for `start_inheritance_claim`,
+/// matching the program's `InheritanceClaim::CLAIM_STATEMENT`. The signing
+/// message uses the vault's current nonce, which the claim does not consume.
+pub const INHERITANCE_CLAIM_PAYLOAD: &[u8] = b"INHERITANCE_CLAIM";
+
+/// Payload for `request_unfreeze`, matching the program's `QuantumVault::UNFREEZE_STATEMENT`.
+pub const UNFREEZE_VAULT_PAYLOAD: &[u8] = b"UNFREEZE_VAULT";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuantumSignedTransaction {
     pub transaction: Vec<u8>,
     pub quantum_signature: Vec<u8>,
     pub algorithm: u8,
+    pub vault: Pubkey,
+    pub nonce: u64,
+    pub expiry_slot: u64,
     pub timestamp: i64,
 }
 
 impl QuantumSignedTransaction {
-    pub fn new(transaction: &[u8], keypair: &Keypair) -> Result<Self> {
-        let signature = keypair.sign(transaction)?;
+    pub fn new(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        nonce: u64,
+        expiry_slot: u64,
+        transaction: &[u8],
+        keypair: &Keypair,
+    ) -> Result<Self> {
+        let message = signing_message(program_id, vault, nonce, expiry_slot, transaction);
+        let signature = keypair.sign(&message)?;
         
         Ok(Self {
             transaction: transaction.to_vec(),
             quantum_signature: signature,
             algorithm: keypair.algorithm.to_u8(),
+            vault: *vault,
+            nonce,
+            expiry_slot,
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -26,3 +61,371 @@ impl QuantumSignedTransaction {
         })
     }
 }
+
+/// PQ and Ed25519 signatures over the same canonical message, for vaults with
+/// a hybrid signature policy. The Ed25519 half is checked on-chain through the
+/// Ed25519 precompile, so `ed25519_instruction` must precede the vault
+/// instruction in the same transaction.
+#[derive(Debug, Clone)]
+pub struct HybridSignature {
+    pub message: Vec<u8>,
+    pub quantum_signature: Vec<u8>,
+    pub ed25519_signature: Signature,
+    pub owner: Pubkey,
+}
+
+impl HybridSignature {
+    pub fn new(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        nonce: u64,
+        expiry_slot: u64,
+        payload: &[u8],
+        keypair: &Keypair,
+        owner: &Ed25519Keypair,
+    ) -> Result<Self> {
+        let message = signing_message(program_id, vault, nonce, expiry_slot, payload);
+        let quantum_signature = keypair.sign(&message)?;
+        let ed25519_signature = owner.sign_message(&message);
+        
+        Ok(Self {
+            message,
+            quantum_signature,
+            ed25519_signature,
+            owner: owner.pubkey(),
+        })
+    }
+    
+    pub fn ed25519_instruction(&self) -> Instruction {
+        ed25519_instruction(&self.owner, &self.ed25519_signature, &self.message)
+    }
+}
+
+/// Ed25519 precompile instruction verifying `signature` by `signer` over
+/// `message`, with all three stored in the instruction's own data.
+pub fn ed25519_instruction(signer: &Pubkey, signature: &Signature, message: &[u8]) -> Instruction {
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+    
+    let mut data = vec![1u8, 0];
+    for field in [
+        SIGNATURE_OFFSET,
+        u16::MAX,
+        PUBLIC_KEY_OFFSET,
+        u16::MAX,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+    
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Builds the canonical message a vault key signs for `payload`: the domain tag
+/// followed by program id, vault, nonce, expiry slot and the payload's SHA-256.
+pub fn signing_message(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    nonce: u64,
+    expiry_slot: u64,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut message = SIGNING_DOMAIN_TAG.to_vec();
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(vault.as_ref());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry_slot.to_le_bytes());
+    message.extend_from_slice(hash(payload).as_ref());
+    message
+}
+
+/// Payload for `rotate_keys`, encoded like the program's `RotationStatement`.
+/// Must be signed by the vault's current PQ key.
+pub fn rotation_payload(old_public_key: &[u8], new_public_key: &[u8]) -> Vec<u8> {
+    let mut payload = hash(old_public_key).to_bytes().to_vec();
+    payload.extend_from_slice(hash(new_public_key).as_ref());
+    payload
+}
+
+/// Payload for `propose_owner`, encoded like the program's `OwnerTransferStatement`.
+pub fn owner_transfer_payload(new_owner: &Pubkey) -> Vec<u8> {
+    new_owner.to_bytes().to_vec()
+}
+
+/// Payload for `set_guardians`, encoded like the program's `RecoveryConfig`.
+pub fn guardians_payload(guardians: &[Pubkey], threshold: u8, delay: i64) -> Vec<u8> {
+    let mut payload = (guardians.len() as u32).to_le_bytes().to_vec();
+    for guardian in guardians {
+        payload.extend_from_slice(guardian.as_ref());
+    }
+    payload.push(threshold);
+    payload.extend_from_slice(&delay.to_le_bytes());
+    payload
+}
+
+/// Payload for `cancel_recovery`, matching the program's
+/// `RecoveryProposal::cancel_statement`.
+pub fn cancel_recovery_payload(new_public_key: &[u8]) -> Vec<u8> {
+    let mut payload = b"CANCEL_RECOVERY".to_vec();
+    payload.extend_from_slice(hash(new_public_key).as_ref());
+    payload
+}
+
+/// Time lock for withdrawals of `mint` (`None` for SOL) of at least
+/// `min_amount`, mirroring the program's `WithdrawalTier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalTier {
+    pub mint: Option<Pubkey>,
+    pub min_amount: u64,
+    pub delay: i64,
+}
+
+/// Payload for `set_withdrawal_tiers`, the Borsh encoding of `Vec<WithdrawalTier>`.
+pub fn withdrawal_tiers_payload(tiers: &[WithdrawalTier]) -> Vec<u8> {
+    let mut payload = (tiers.len() as u32).to_le_bytes().to_vec();
+    for tier in tiers {
+        match tier.mint {
+            Some(mint) => {
+                payload.push(1);
+                payload.extend_from_slice(mint.as_ref());
+            }
+            None => payload.push(0),
+        }
+        payload.extend_from_slice(&tier.min_amount.to_le_bytes());
+        payload.extend_from_slice(&tier.delay.to_le_bytes());
+    }
+    payload
+}
+
+/// At most `max_amount` of `mint` (`None` for SOL) per `period` seconds,
+/// mirroring the program's `SpendingLimitConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendingLimit {
+    pub mint: Option<Pubkey>,
+    pub period: i64,
+    pub max_amount: u64,
+}
+
+/// Payload for `set_spending_limits`, the Borsh encoding of
+/// `Vec<SpendingLimitConfig>`. Raised limits only apply after a two-day delay.
+pub fn spending_limits_payload(limits: &[SpendingLimit]) -> Vec<u8> {
+    let mut payload = (limits.len() as u32).to_le_bytes().to_vec();
+    for limit in limits {
+        match limit.mint {
+            Some(mint) => {
+                payload.push(1);
+                payload.extend_from_slice(mint.as_ref());
+            }
+            None => payload.push(0),
+        }
+        payload.extend_from_slice(&limit.period.to_le_bytes());
+        payload.extend_from_slice(&limit.max_amount.to_le_bytes());
+    }
+    payload
+}
+
+/// Payload for `add_address_book_entry`, encoded like the program's
+/// `AddressBookChange::Add`. For SPL mints `destination` is the token account.
+pub fn address_book_add_payload(destination: &Pubkey, mint: Option<&Pubkey>, cap: Option<u64>) -> Vec<u8> {
+    let mut payload = vec![0u8];
+    payload.extend_from_slice(destination.as_ref());
+    push_option(&mut payload, mint.map(|mint| mint.to_bytes().to_vec()));
+    push_option(&mut payload, cap.map(|cap| cap.to_le_bytes().to_vec()));
+    payload
+}
+
+/// Payload for `remove_address_book_entry`, encoded like the program's
+/// `AddressBookChange::Remove`.
+pub fn address_book_remove_payload(destination: &Pubkey, mint: Option<&Pubkey>) -> Vec<u8> {
+    let mut payload = vec![1u8];
+    payload.extend_from_slice(destination.as_ref());
+    push_option(&mut payload, mint.map(|mint| mint.to_bytes().to_vec()));
+    payload
+}
+
+/// Appends a Borsh `Option`: a presence byte followed by the encoded value.
+fn push_option(payload: &mut Vec<u8>, value: Option<Vec<u8>>) {
+    match value {
+        Some(bytes) => {
+            payload.push(1);
+            payload.extend_from_slice(&bytes);
+        }
+        None => payload.push(0),
+    }
+}
+
+/// Key delegated by `grant_session`, mirroring the program's `SessionKey`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionKey {
+    Ed25519(Pubkey),
+    PostQuantum { algorithm: Algorithm, public_key: Vec<u8> },
+}
+
+/// Limits on a session grant, mirroring the program's `SessionScope`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionScope {
+    pub allowed_programs: Vec<Pubkey>,
+    pub max_amount: u64,
+    pub expiry_slot: u64,
+}
+
+/// Payload for `grant_session`, encoded like the program's
+/// `SessionGrantStatement`. Must be signed by the vault's root PQ key.
+///
+/// Session operations are then signed by the session key over
+/// `signing_message` with the grant's address in place of the vault's.
+pub fn session_grant_payload(session_key: &SessionKey, scope: &SessionScope) -> Vec<u8> {
+    let mut payload = match session_key {
+        SessionKey::Ed25519(key) => {
+            let mut payload = vec![0u8];
+            payload.extend_from_slice(key.as_ref());
+            payload
+        }
+        SessionKey::PostQuantum { algorithm, public_key } => {
+            let mut payload = vec![1u8, algorithm.to_u8()];
+            payload.extend_from_slice(&(public_key.len() as u32).to_le_bytes());
+            payload.extend_from_slice(public_key);
+            payload
+        }
+    };
+    payload.extend_from_slice(&(scope.allowed_programs.len() as u32).to_le_bytes());
+    for program_id in &scope.allowed_programs {
+        payload.extend_from_slice(program_id.as_ref());
+    }
+    payload.extend_from_slice(&scope.max_amount.to_le_bytes());
+    payload.extend_from_slice(&scope.expiry_slot.to_le_bytes());
+    payload
+}
+
+/// Dead-man switch settings, mirroring the program's `InheritanceConfig`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InheritanceConfig {
+    pub beneficiary: Pubkey,
+    pub algorithm: Algorithm,
+    pub public_key: Vec<u8>,
+    pub inactivity_period: i64,
+    pub claim_delay: i64,
+}
+
+/// Payload for `set_beneficiary`, the Borsh encoding of
+/// `Option<InheritanceConfig>`; `None` removes the beneficiary.
+pub fn inheritance_payload(inheritance: Option<&InheritanceConfig>) -> Vec<u8> {
+    let Some(config) = inheritance else {
+        return vec![0u8];
+    };
+    
+    let mut payload = vec![1u8];
+    payload.extend_from_slice(config.beneficiary.as_ref());
+    payload.push(config.algorithm.to_u8());
+    payload.extend_from_slice(hash(&config.public_key).as_ref());
+    payload.extend_from_slice(&config.inactivity_period.to_le_bytes());
+    payload.extend_from_slice(&config.claim_delay.to_le_bytes());
+    payload
+}
+
+/// Payload for `cancel_withdrawal`, matching the program's
+/// `PendingWithdrawal::cancel_statement`.
+pub fn cancel_withdrawal_payload(pending_withdrawal: &Pubkey) -> Vec<u8> {
+    let mut payload = b"CANCEL_WITHDRAWAL".to_vec();
+    payload.extend_from_slice(pending_withdrawal.as_ref());
+    payload
+}
+
+/// Payload for `migrate_algorithm`, encoded like the program's
+/// `MigrationStatement`. Must be signed by the vault's current PQ key.
+pub fn migration_payload(
+    new_algorithm: Algorithm,
+    old_public_key: &[u8],
+    new_public_key: &[u8],
+) -> Vec<u8> {
+    let mut payload = vec![new_algorithm.to_u8()];
+    payload.extend_from_slice(&rotation_payload(old_public_key, new_public_key));
+    payload
+}
+
+/// Payload for `publish_encryption_key`, encoded like the program's
+/// `EncryptionKeyStatement`.
+pub fn encryption_key_payload(algorithm: Algorithm, public_key: &[u8]) -> Vec<u8> {
+    let mut payload = vec![algorithm.to_u8()];
+    payload.extend_from_slice(hash(public_key).as_ref());
+    payload
+}
+
+/// Payload for `withdraw_sol`, encoded like the program's `VaultAction::WithdrawSol`.
+pub fn withdraw_sol_payload(destination: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut payload = vec![0u8];
+    payload.extend_from_slice(destination.as_ref());
+    payload.extend_from_slice(&amount.to_le_bytes());
+    payload
+}
+
+/// Payload for `withdraw_spl`, encoded like the program's `VaultAction::WithdrawSpl`.
+pub fn withdraw_spl_payload(mint: &Pubkey, destination: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut payload = vec![1u8];
+    payload.extend_from_slice(mint.as_ref());
+    payload.extend_from_slice(destination.as_ref());
+    payload.extend_from_slice(&amount.to_le_bytes());
+    payload
+}
+
+/// Payload for `execute`, encoded like the program's `VaultAction::Execute`.
+pub fn execute_payload(instructions: &[Instruction]) -> Vec<u8> {
+    let mut payload = vec![2u8];
+    payload.extend_from_slice(&instructions_hash(instructions));
+    payload
+}
+
+/// SHA-256 of the Borsh-encoded `Vec<VaultInstruction>` passed to `execute`.
+pub fn instructions_hash(instructions: &[Instruction]) -> [u8; 32] {
+    let mut encoded = (instructions.len() as u32).to_le_bytes().to_vec();
+    for instruction in instructions {
+        encoded.extend_from_slice(instruction.program_id.as_ref());
+        encoded.extend_from_slice(&(instruction.accounts.len() as u32).to_le_bytes());
+        for meta in &instruction.accounts {
+            encoded.extend_from_slice(meta.pubkey.as_ref());
+            encoded.push(meta.is_signer as u8);
+            encoded.push(meta.is_writable as u8);
+        }
+        encoded.extend_from_slice(&(instruction.data.len() as u32).to_le_bytes());
+        encoded.extend_from_slice(&instruction.data);
+    }
+    hash(&encoded).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_signing_message_binds_nonce() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let first = signing_message(&program_id, &vault, 0, 100, b"payload");
+        let second = signing_message(&program_id, &vault, 1, 100, b"payload");
+        assert_eq!(first.len(), SIGNING_DOMAIN_TAG.len() + 32 + 32 + 8 + 8 + 32);
+        assert_ne!(first, second);
+    }
+    
+    #[test]
+    fn test_ed25519_instruction_layout() {
+        let owner = Ed25519Keypair::new();
+        let message = signing_message(&Pubkey::new_unique(), &Pubkey::new_unique(), 0, 100, b"payload");
+        let signature = owner.sign_message(&message);
+        let instruction = ed25519_instruction(&owner.pubkey(), &signature, &message);
+        
+        assert_eq!(&instruction.data[16..48], owner.pubkey().as_ref());
+        assert_eq!(&instruction.data[48..112], signature.as_ref());
+        assert_eq!(&instruction.data[112..], &message[..]);
+        assert!(signature.verify(owner.pubkey().as_ref(), &instruction.data[112..]));
+    }
+}
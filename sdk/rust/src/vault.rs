@@ -1,6 +1,6 @@
 //! Quantum-safe vault management
 
-use crate::{Result, crypto::Algorithm};
+use crate::{Result, SdkError, crypto::Algorithm};
 use solana_sdk::pubkey::Pubkey;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +13,115 @@ pub struct QuantumVault {
     pub created_at: i64,
     pub last_key_rotation: i64,
     pub transaction_count: u64,
+    pub nonce: u64,
+    pub signature_policy: SignaturePolicy,
+    pub vault_index: u64,
+    pub label: String,
+    pub rotation_policy: RotationPolicy,
+    pub creator: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+}
+
+/// Key rotation deadline and warning window in seconds, mirroring the
+/// program's `RotationPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RotationPolicy {
+    pub period: i64,
+    pub grace_period: i64,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            period: 90 * 24 * 60 * 60,
+            grace_period: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Address of vault number `index` created by `creator`. It stays the same
+/// when ownership is transferred, so `creator` need not be the current owner.
+pub fn vault_address(program_id: &Pubkey, creator: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"vault", creator.as_ref(), &index.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Address of the registry counting the vaults `creator` has created.
+pub fn registry_address(program_id: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"registry", creator.as_ref()], program_id)
+}
+
+/// Address of the withdrawal queued on `vault` with `nonce`.
+pub fn pending_withdrawal_address(program_id: &Pubkey, vault: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"withdrawal", vault.as_ref(), &nonce.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Address of the session grant created on `vault` with `nonce`.
+pub fn session_grant_address(program_id: &Pubkey, vault: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"session_grant", vault.as_ref(), &nonce.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Address of the verification session for the signature in
+/// `signature_buffer`, made on behalf of `subject`: the vault for its own key
+/// and the beneficiary's, or the session grant for a PQ session key.
+pub fn verification_session_address(
+    program_id: &Pubkey,
+    subject: &Pubkey,
+    signature_buffer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"session", subject.as_ref(), signature_buffer.as_ref()],
+        program_id,
+    )
+}
+
+/// Address of the beneficiary's pending claim on `vault`.
+pub fn inheritance_claim_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"inheritance_claim", vault.as_ref()], program_id)
+}
+
+/// Address of `vault`'s log of retired keys.
+pub fn key_history_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"key_history", vault.as_ref()], program_id)
+}
+
+/// Address of `vault`'s destination allowlist.
+pub fn address_book_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"address_book", vault.as_ref()], program_id)
+}
+
+/// Addresses of all `vault_count` vaults recorded in `creator`'s registry.
+pub fn vault_addresses(program_id: &Pubkey, creator: &Pubkey, vault_count: u64) -> Vec<Pubkey> {
+    (0..vault_count)
+        .map(|index| vault_address(program_id, creator, index).0)
+        .collect()
+}
+
+/// Signatures a vault requires, mirroring the program's `SignaturePolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignaturePolicy {
+    PqOnly,
+    ClassicalOnly,
+    HybridBoth,
+    HybridEither,
+}
+
+impl SignaturePolicy {
+    pub fn requires_pq(&self) -> bool {
+        matches!(self, SignaturePolicy::PqOnly | SignaturePolicy::HybridBoth)
+    }
+    
+    pub fn requires_classical(&self) -> bool {
+        matches!(self, SignaturePolicy::ClassicalOnly | SignaturePolicy::HybridBoth)
+    }
 }
 
 impl QuantumVault {
@@ -21,7 +130,162 @@ impl QuantumVault {
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs() as i64;
-        const ROTATION_PERIOD: i64 = 90 * 24 * 60 * 60;
-        now - self.last_key_rotation > ROTATION_PERIOD
+        now - self.last_key_rotation > self.rotation_policy.period
+    }
+    
+    /// Mirrors the program's warning window before the rotation deadline.
+    pub fn is_key_rotation_due_soon(&self) -> bool {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let elapsed = now - self.last_key_rotation;
+        let policy = self.rotation_policy;
+        elapsed <= policy.period && elapsed > policy.period - policy.grace_period
+    }
+}
+
+/// Why a key stopped being the vault key, mirroring the program's
+/// `KeyRetirementReason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyRetirementReason {
+    Rotation,
+    Migration,
+    Recovery,
+    Inheritance,
+}
+
+/// A period during which one key was the vault key. `retired_at` and
+/// `reason` are `None` for the current key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyPeriod {
+    pub key_hash: [u8; 32],
+    pub algorithm: Algorithm,
+    pub activated_at: i64,
+    pub retired_at: Option<i64>,
+    pub reason: Option<KeyRetirementReason>,
+}
+
+/// Decoded `KeyHistory` account: a ring buffer of retired keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyHistory {
+    pub vault: Pubkey,
+    pub head: u32,
+    pub total_recorded: u64,
+    pub entries: Vec<KeyPeriod>,
+}
+
+impl KeyHistory {
+    pub const MAX_ENTRIES: usize = 32;
+    const ENTRY_SIZE: usize = 32 + 1 + 8 + 8 + 1;
+    
+    /// Decodes raw account data, including the 8-byte Anchor discriminator.
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        let invalid = || SdkError::SerializationError("Malformed key history account".to_string());
+        let read = |offset: usize, len: usize| data.get(offset..offset + len).ok_or_else(invalid);
+        
+        let vault = Pubkey::try_from(read(8, 32)?).map_err(|_| invalid())?;
+        let head = u32::from_le_bytes(read(40, 4)?.try_into().unwrap());
+        let total_recorded = u64::from_le_bytes(read(44, 8)?.try_into().unwrap());
+        let count = u32::from_le_bytes(read(52, 4)?.try_into().unwrap()) as usize;
+        
+        let mut entries = Vec::with_capacity(count);
+        for index in 0..count {
+            let entry = read(56 + index * Self::ENTRY_SIZE, Self::ENTRY_SIZE)?;
+            let reason = match entry[49] {
+                0 => KeyRetirementReason::Rotation,
+                1 => KeyRetirementReason::Migration,
+                2 => KeyRetirementReason::Recovery,
+                3 => KeyRetirementReason::Inheritance,
+                _ => return Err(invalid()),
+            };
+            entries.push(KeyPeriod {
+                key_hash: entry[..32].try_into().unwrap(),
+                algorithm: Algorithm::from_u8(entry[32])?,
+                activated_at: i64::from_le_bytes(entry[33..41].try_into().unwrap()),
+                retired_at: Some(i64::from_le_bytes(entry[41..49].try_into().unwrap())),
+                reason: Some(reason),
+            });
+        }
+        
+        Ok(Self { vault, head, total_recorded, entries })
+    }
+    
+    /// Retained retired keys, oldest first.
+    pub fn chronological(&self) -> Vec<&KeyPeriod> {
+        let split = if self.entries.len() < Self::MAX_ENTRIES { 0 } else { self.head as usize };
+        self.entries[split..].iter().chain(&self.entries[..split]).collect()
+    }
+    
+    /// The key that was the vault key at `timestamp`, with `vault` supplying
+    /// the current key. `None` before the vault existed or for times whose
+    /// entries have been overwritten.
+    pub fn key_active_at(&self, vault: &QuantumVault, timestamp: i64) -> Option<KeyPeriod> {
+        if timestamp >= vault.last_key_rotation {
+            return Some(KeyPeriod {
+                key_hash: solana_sdk::hash::hash(&vault.public_key).to_bytes(),
+                algorithm: vault.algorithm,
+                activated_at: vault.last_key_rotation,
+                retired_at: None,
+                reason: None,
+            });
+        }
+        
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.activated_at <= timestamp
+                    && entry.retired_at.map_or(false, |retired_at| timestamp < retired_at)
+            })
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn account_data(head: u32, entries: &[(u8, i64, i64)]) -> Vec<u8> {
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&head.to_le_bytes());
+        data.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+        data.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        for (key, activated_at, retired_at) in entries {
+            data.extend_from_slice(&[*key; 32]);
+            data.push(0);
+            data.extend_from_slice(&activated_at.to_le_bytes());
+            data.extend_from_slice(&retired_at.to_le_bytes());
+            data.push(0);
+        }
+        data.push(255);
+        data
+    }
+    
+    #[test]
+    fn test_key_history_lookup() {
+        let history = KeyHistory::from_account_data(&account_data(2, &[(1, 100, 200), (2, 200, 300)])).unwrap();
+        let vault = QuantumVault {
+            address: Pubkey::default(),
+            owner: Pubkey::default(),
+            algorithm: Algorithm::Dilithium3,
+            public_key: vec![3u8; 8],
+            created_at: 100,
+            last_key_rotation: 300,
+            transaction_count: 0,
+            nonce: 0,
+            signature_policy: SignaturePolicy::PqOnly,
+            vault_index: 0,
+            label: String::new(),
+            rotation_policy: RotationPolicy::default(),
+            creator: Pubkey::default(),
+            pending_owner: None,
+        };
+        
+        assert_eq!(history.key_active_at(&vault, 150).unwrap().key_hash, [1u8; 32]);
+        assert_eq!(history.key_active_at(&vault, 200).unwrap().key_hash, [2u8; 32]);
+        assert_eq!(history.key_active_at(&vault, 300).unwrap().retired_at, None);
+        assert!(history.key_active_at(&vault, 50).is_none());
+        assert_eq!(history.chronological()[0].activated_at, 100);
     }
 }
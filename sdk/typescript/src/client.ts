@@ -1,23 +1,54 @@
-import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
-import { Program, AnchorProvider, Idl } from '@coral-xyz/anchor';
-import { Algorithm, QuantumVaultAccount, VaultConfig } from './types';
+import {
+  Connection,
+  Ed25519Program,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js';
+import { Program, AnchorProvider, Idl, BN } from '@coral-xyz/anchor';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from '@solana/spl-token';
+import { createHash } from 'crypto';
+import {
+  AddressBookAccount,
+  Algorithm,
+  EncryptionKeyRecordAccount,
+  InheritanceClaimAccount,
+  KeyHistoryAccount,
+  KeyPeriod,
+  KeyRetirementReason,
+  PendingWithdrawalAccount,
+  QuantumVaultAccount,
+  RecoveryProposalAccount,
+  SessionGrantAccount,
+  SignaturePolicy,
+  VaultConfig,
+  VaultRegistryAccount,
+} from './types';
+
+export interface HybridSignature {
+  quantumSignature: Buffer;
+  ed25519Instruction: TransactionInstruction;
+}
 
 export class QuantumVaultClient {
   private program: Program;
   private connection: Connection;
   private provider: AnchorProvider;
+  private wallet: any;
 
   constructor(connection: Connection, wallet: any, programId: PublicKey, idl: Idl) {
     this.connection = connection;
+    this.wallet = wallet;
     this.provider = new AnchorProvider(connection, wallet, { commitment: 'confirmed' });
     this.program = new Program(idl, programId, this.provider);
   }
 
   async initialize(authority: PublicKey): Promise<string> {
-    const [globalStatePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('global')],
-      this.program.programId
-    );
+    const globalStatePda = this.getGlobalStateAddress();
 
     const tx = await this.program.methods
       .initialize(authority)
@@ -33,15 +64,25 @@ export class QuantumVaultClient {
 
   async createVault(config: VaultConfig, publicKey: Buffer): Promise<PublicKey> {
     const owner = this.provider.wallet.publicKey;
-    
-    const [vaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('vault'), owner.toBuffer()],
-      this.program.programId
-    );
+    const registry = this.getRegistryAddress(owner);
+    const vaultPda = this.getVaultAddress(owner, await this.getVaultCount(owner));
 
     await this.program.methods
-      .createVault(config.algorithm, Array.from(publicKey))
+      .createVault(
+        config.algorithm,
+        Array.from(publicKey),
+        QuantumVaultClient.policyArg(config.signaturePolicy ?? SignaturePolicy.PqOnly),
+        config.label ?? '',
+        config.rotationPeriod === undefined
+          ? null
+          : {
+              period: new BN(config.rotationPeriod),
+              gracePeriod: new BN(config.rotationGracePeriod ?? 7 * 24 * 60 * 60),
+            }
+      )
       .accounts({
+        globalState: this.getGlobalStateAddress(),
+        registry,
         vault: vaultPda,
         owner,
         systemProgram: SystemProgram.programId,
@@ -51,16 +92,506 @@ export class QuantumVaultClient {
     return vaultPda;
   }
 
+  async setRotationPolicy(vault: PublicKey, period: number, gracePeriod: number): Promise<string> {
+    return this.program.methods
+      .setRotationPolicy({ period: new BN(period), gracePeriod: new BN(gracePeriod) })
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        owner: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Completes a transfer proposed by the vault's PQ key; the wallet must be the pending owner. */
+  async acceptOwner(vault: PublicKey): Promise<string> {
+    return this.program.methods
+      .acceptOwner()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        newOwner: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Opens a recovery proposal as a guardian, counting as its first approval. */
+  async proposeRecovery(vault: PublicKey, newKeyHash: Buffer): Promise<string> {
+    return this.program.methods
+      .proposeRecovery(Array.from(newKeyHash))
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        recovery: this.getRecoveryAddress(vault, this.provider.wallet.publicKey),
+        guardian: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async approveRecovery(vault: PublicKey, proposer: PublicKey): Promise<string> {
+    return this.program.methods
+      .approveRecovery()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        recovery: this.getRecoveryAddress(vault, proposer),
+        guardian: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Clears `proposer`'s expired or stale proposal, refunding its rent to them. */
+  async closeRecovery(vault: PublicKey, proposer: PublicKey): Promise<string> {
+    return this.program.methods
+      .closeRecovery()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        recovery: this.getRecoveryAddress(vault, proposer),
+        proposer,
+      })
+      .rpc();
+  }
+
+  async getRecoveryProposal(vault: PublicKey, proposer: PublicKey): Promise<RecoveryProposalAccount | null> {
+    const account = await this.program.account.recoveryProposal.fetchNullable(
+      this.getRecoveryAddress(vault, proposer)
+    );
+    return account as RecoveryProposalAccount | null;
+  }
+
+  /** Marks the vault as active, voiding any inheritance claim started earlier. */
+  async heartbeat(vault: PublicKey): Promise<string> {
+    return this.program.methods
+      .heartbeat()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        owner: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  async getInheritanceClaim(vault: PublicKey): Promise<InheritanceClaimAccount | null> {
+    const account = await this.program.account.inheritanceClaim.fetchNullable(
+      this.getInheritanceClaimAddress(vault)
+    );
+    return account as InheritanceClaimAccount | null;
+  }
+
+  /** Halts `vault`; the wallet must be its owner, a guardian or the protocol authority. */
+  async freezeVault(vault: PublicKey): Promise<string> {
+    return this.program.methods
+      .freezeVault()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        signer: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Lifts a freeze whose PQ-signed `requestUnfreeze` delay has passed. */
+  async unfreezeVault(vault: PublicKey): Promise<string> {
+    return this.program.methods
+      .unfreezeVault()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+      })
+      .rpc();
+  }
+
+  async revokeSession(vault: PublicKey, sessionGrant: PublicKey): Promise<string> {
+    return this.program.methods
+      .revokeSession()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        sessionGrant,
+        owner: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  async getSessionGrant(sessionGrant: PublicKey): Promise<SessionGrantAccount> {
+    const account = await this.program.account.sessionGrant.fetch(sessionGrant);
+    return account as SessionGrantAccount;
+  }
+
+  async getKeyHistory(vault: PublicKey): Promise<KeyHistoryAccount | null> {
+    const account = await this.program.account.keyHistory.fetchNullable(
+      this.getKeyHistoryAddress(vault)
+    );
+    return account as KeyHistoryAccount | null;
+  }
+
+  /**
+   * Answers which key was the vault key at `timestamp` (unix seconds). Returns
+   * null before the vault existed or once the covering entry has been
+   * overwritten in the history's ring buffer.
+   */
+  async keyActiveAt(vault: PublicKey, timestamp: number): Promise<KeyPeriod | null> {
+    const account = await this.getVault(vault);
+    if (timestamp >= account.lastKeyRotation.toNumber()) {
+      return {
+        keyHash: createHash('sha256').update(account.publicKey).digest(),
+        algorithm: account.algorithm,
+        activatedAt: account.lastKeyRotation.toNumber(),
+        retiredAt: null,
+        reason: null,
+      };
+    }
+
+    const history = await this.getKeyHistory(vault);
+    const entry = history?.entries.find(
+      (e) => e.activatedAt.toNumber() <= timestamp && timestamp < e.retiredAt.toNumber()
+    );
+    if (!entry) {
+      return null;
+    }
+
+    return {
+      keyHash: Buffer.from(entry.keyHash),
+      algorithm: entry.algorithm,
+      activatedAt: entry.activatedAt.toNumber(),
+      retiredAt: entry.retiredAt.toNumber(),
+      reason: Object.keys(entry.reason)[0] as KeyRetirementReason,
+    };
+  }
+
+  /** Fetches a vault's destination allowlist, or null if it has none. */
+  async getAddressBook(vault: PublicKey): Promise<AddressBookAccount | null> {
+    const account = await this.program.account.addressBook.fetchNullable(
+      this.getAddressBookAddress(vault)
+    );
+    return account as AddressBookAccount | null;
+  }
+
+  /**
+   * Releases a queued withdrawal once its unlock time has passed. SPL
+   * withdrawals also need the vault's token account.
+   */
+  async executeWithdrawal(
+    vault: PublicKey,
+    pendingWithdrawal: PublicKey,
+    vaultTokenAccount?: PublicKey
+  ): Promise<string> {
+    const pending = (await this.program.account.pendingWithdrawal.fetch(
+      pendingWithdrawal
+    )) as PendingWithdrawalAccount;
+    const { destination } = pending.action.withdrawSol ?? pending.action.withdrawSpl;
+    const vaultAccount = await this.getVault(vault);
+
+    return this.program.methods
+      .executeWithdrawal()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        pendingWithdrawal,
+        destination,
+        addressBook: vaultAccount.hasAddressBook ? this.getAddressBookAddress(vault) : null,
+        vaultTokenAccount: vaultTokenAccount ?? null,
+        payer: pending.payer,
+        tokenProgram: vaultTokenAccount ? TOKEN_PROGRAM_ID : null,
+      })
+      .rpc();
+  }
+
+  /**
+   * Opens the vault's token account for `mint`, which SPL withdrawals require
+   * and `close_vault` must close.
+   */
+  async openTokenAccount(vault: PublicKey, mint: PublicKey): Promise<PublicKey> {
+    const vaultTokenAccount = getAssociatedTokenAddressSync(mint, vault, true);
+
+    await this.program.methods
+      .openTokenAccount()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        vault,
+        mint,
+        vaultTokenAccount,
+        owner: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return vaultTokenAccount;
+  }
+
+  /**
+   * Session verifying the signature in `signatureBuffer` for `subject`: the
+   * vault for its own key and the beneficiary's, or the grant for a PQ session key.
+   */
+  getVerificationSessionAddress(subject: PublicKey, signatureBuffer: PublicKey): PublicKey {
+    const [sessionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('session'), subject.toBuffer(), signatureBuffer.toBuffer()],
+      this.program.programId
+    );
+    return sessionPda;
+  }
+
+  getInheritanceClaimAddress(vault: PublicKey): PublicKey {
+    const [claimPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('inheritance_claim'), vault.toBuffer()],
+      this.program.programId
+    );
+    return claimPda;
+  }
+
+  getKeyHistoryAddress(vault: PublicKey): PublicKey {
+    const [historyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('key_history'), vault.toBuffer()],
+      this.program.programId
+    );
+    return historyPda;
+  }
+
+  getAddressBookAddress(vault: PublicKey): PublicKey {
+    const [addressBookPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('address_book'), vault.toBuffer()],
+      this.program.programId
+    );
+    return addressBookPda;
+  }
+
+  getSessionGrantAddress(vault: PublicKey, nonce: number | BN): PublicKey {
+    const [grantPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('session_grant'), vault.toBuffer(), new BN(nonce).toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return grantPda;
+  }
+
+  getPendingWithdrawalAddress(vault: PublicKey, nonce: number | BN): PublicKey {
+    const [pendingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('withdrawal'), vault.toBuffer(), new BN(nonce).toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return pendingPda;
+  }
+
+  /** Number of vaults `owner` has created; also the index of their next vault. */
+  async getVaultCount(owner: PublicKey): Promise<number> {
+    const registry = await this.program.account.vaultRegistry.fetchNullable(
+      this.getRegistryAddress(owner)
+    );
+    return registry ? (registry as VaultRegistryAccount).vaultCount.toNumber() : 0;
+  }
+
+  /** Proposes `newAuthority` as protocol authority; it takes over once it calls `acceptAuthority`. */
+  async setAuthority(newAuthority: PublicKey): Promise<string> {
+    return this.program.methods
+      .setAuthority(newAuthority)
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  async acceptAuthority(): Promise<string> {
+    return this.program.methods
+      .acceptAuthority()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        newAuthority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  async pauseProtocol(paused: boolean): Promise<string> {
+    return this.program.methods
+      .pauseProtocol(paused)
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  async setMinSecurityLevel(level: 2 | 3 | 5): Promise<string> {
+    return this.program.methods
+      .setMinSecurityLevel(level)
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Replaces the protocol's algorithm allowlist; bit `n` permits `Algorithm` value `n`. */
+  async setAllowedAlgorithms(algorithms: Algorithm[]): Promise<string> {
+    const bitmap = algorithms.reduce((bits, algorithm) => bits | (1 << algorithm), 0);
+    return this.program.methods
+      .setAllowedAlgorithms(bitmap)
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
+  /**
+   * Signs the canonical `message` with both the vault's PQ key (via
+   * `signQuantum`) and the wallet's Ed25519 key. The returned Ed25519 program
+   * instruction must precede the vault instruction in the same transaction.
+   */
+  async signHybrid(
+    message: Buffer,
+    signQuantum: (message: Buffer) => Buffer | Promise<Buffer>
+  ): Promise<HybridSignature> {
+    const quantumSignature = await signQuantum(message);
+    const ed25519Signature: Uint8Array = await this.wallet.signMessage(message);
+
+    return {
+      quantumSignature,
+      ed25519Instruction: Ed25519Program.createInstructionWithPublicKey({
+        publicKey: this.provider.wallet.publicKey.toBytes(),
+        message,
+        signature: ed25519Signature,
+      }),
+    };
+  }
+
+  async uploadBuffer(bufferId: number, payload: Buffer, chunkSize = 900): Promise<PublicKey> {
+    const authority = this.provider.wallet.publicKey;
+    const buffer = this.getBufferAddress(authority, bufferId);
+    const globalState = this.getGlobalStateAddress();
+
+    await this.program.methods
+      .initBuffer(bufferId, payload.length)
+      .accounts({
+        globalState,
+        buffer,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    for (let offset = 0; offset < payload.length; offset += chunkSize) {
+      await this.program.methods
+        .writeChunk(offset, payload.subarray(offset, offset + chunkSize))
+        .accounts({
+          globalState,
+          buffer,
+          authority,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    await this.program.methods
+      .finalize()
+      .accounts({ globalState, buffer, authority })
+      .rpc();
+
+    return buffer;
+  }
+
+  /** Closes an unconsumed buffer of the wallet's, returning its rent. */
+  async closeBuffer(bufferId: number): Promise<string> {
+    const authority = this.provider.wallet.publicKey;
+    return this.program.methods
+      .closeBuffer()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        buffer: this.getBufferAddress(authority, bufferId),
+        authority,
+      })
+      .rpc();
+  }
+
+  /**
+   * Closes an unconsumed verification session, such as one whose signature
+   * failed, together with its signature buffer unless that is already closed.
+   */
+  async closeSession(subject: PublicKey, signatureBuffer: PublicKey): Promise<string> {
+    const bufferInfo = await this.connection.getAccountInfo(signatureBuffer);
+    return this.program.methods
+      .closeSession()
+      .accounts({
+        globalState: this.getGlobalStateAddress(),
+        session: this.getVerificationSessionAddress(subject, signatureBuffer),
+        signatureBuffer: bufferInfo ? signatureBuffer : null,
+        payer: this.provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+
   async getVault(vaultAddress: PublicKey): Promise<QuantumVaultAccount> {
     const account = await this.program.account.quantumVault.fetch(vaultAddress);
     return account as QuantumVaultAccount;
   }
 
-  getVaultAddress(owner: PublicKey): PublicKey {
+  /** Fetches the ML-KEM key a vault has published for encrypted communication. */
+  async getEncryptionKey(vaultAddress: PublicKey): Promise<EncryptionKeyRecordAccount> {
+    const account = await this.program.account.encryptionKeyRecord.fetch(
+      this.getEncryptionKeyAddress(vaultAddress)
+    );
+    return account as EncryptionKeyRecordAccount;
+  }
+
+  getGlobalStateAddress(): PublicKey {
+    const [globalStatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('global')],
+      this.program.programId
+    );
+    return globalStatePda;
+  }
+
+  /** Stable vault address from its creator and index; unaffected by ownership transfers. */
+  getVaultAddress(creator: PublicKey, index = 0): PublicKey {
     const [vaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('vault'), owner.toBuffer()],
+      [Buffer.from('vault'), creator.toBuffer(), new BN(index).toArrayLike(Buffer, 'le', 8)],
       this.program.programId
     );
     return vaultPda;
   }
+
+  getRegistryAddress(owner: PublicKey): PublicKey {
+    const [registryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('registry'), owner.toBuffer()],
+      this.program.programId
+    );
+    return registryPda;
+  }
+
+  getBufferAddress(authority: PublicKey, bufferId: number): PublicKey {
+    const [bufferPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('buffer'), authority.toBuffer(), new BN(bufferId).toArrayLike(Buffer, 'le', 4)],
+      this.program.programId
+    );
+    return bufferPda;
+  }
+
+  getEncryptionKeyAddress(vault: PublicKey): PublicKey {
+    const [recordPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('encryption_key'), vault.toBuffer()],
+      this.program.programId
+    );
+    return recordPda;
+  }
+
+  getRecoveryAddress(vault: PublicKey, proposer: PublicKey): PublicKey {
+    const [recoveryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('recovery'), vault.toBuffer(), proposer.toBuffer()],
+      this.program.programId
+    );
+    return recoveryPda;
+  }
+
+  private static policyArg(policy: SignaturePolicy): Record<string, {}> {
+    const names: Record<SignaturePolicy, string> = {
+      [SignaturePolicy.PqOnly]: 'pqOnly',
+      [SignaturePolicy.ClassicalOnly]: 'classicalOnly',
+      [SignaturePolicy.HybridBoth]: 'hybridBoth',
+      [SignaturePolicy.HybridEither]: 'hybridEither',
+    };
+    return { [names[policy]]: {} };
+  }
 }
@@ -20,8 +20,8 @@ export class CryptoUtils {
   static getSignatureSize(algorithm: Algorithm): number {
     const sizes: Record<Algorithm, number> = {
       [Algorithm.Dilithium2]: 2420,
-      [Algorithm.Dilithium3]: 3293,
-      [Algorithm.Dilithium5]: 4595,
+      [Algorithm.Dilithium3]: 3309,
+      [Algorithm.Dilithium5]: 4627,
       [Algorithm.SphincsSha2128s]: 7856,
       [Algorithm.SphincsSha2128f]: 17088,
       [Algorithm.SphincsShake128s]: 7856,
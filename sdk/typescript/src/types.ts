@@ -7,6 +7,7 @@ export enum Algorithm {
   Dilithium5 = 2,
   SphincsSha2128s = 3,
   SphincsSha2128f = 4,
+  /** Too costly to verify on-chain; the program rejects SHAKE keys. */
   SphincsShake128s = 5,
   SphincsShake128f = 6,
   Kyber512 = 7,
@@ -14,6 +15,13 @@ export enum Algorithm {
   Kyber1024 = 9,
 }
 
+export enum SignaturePolicy {
+  PqOnly = 0,
+  ClassicalOnly = 1,
+  HybridBoth = 2,
+  HybridEither = 3,
+}
+
 export interface QuantumVaultAccount {
   owner: PublicKey;
   algorithm: Algorithm;
@@ -23,6 +31,196 @@ export interface QuantumVaultAccount {
   transactionCount: BN;
   complianceStatus: ComplianceStatus;
   bump: number;
+  nonce: BN;
+  signaturePolicy: SignaturePolicy;
+  vaultIndex: BN;
+  label: string;
+  rotationPolicy: RotationPolicy;
+  creator: PublicKey;
+  pendingOwner: PublicKey | null;
+  ownerEpoch: number;
+  recovery: RecoveryConfig;
+  withdrawalTiers: WithdrawalTier[];
+  pendingWithdrawalTiers: { tiers: WithdrawalTier[]; effectiveAt: BN } | null;
+  spendingLimits: SpendingLimit[];
+  pendingSpendingLimits: { limits: SpendingLimitConfig[]; effectiveAt: BN } | null;
+  hasAddressBook: boolean;
+  hasKeyHistory: boolean;
+  hasEncryptionKey: boolean;
+  tokenMints: PublicKey[];
+  frozen: boolean;
+  unfreezeAt: BN | null;
+  lastActivity: BN;
+  activityCount: BN;
+  inheritance: InheritanceConfig | null;
+}
+
+export enum KeyRetirementReason {
+  Rotation = 'rotation',
+  Migration = 'migration',
+  Recovery = 'recovery',
+  Inheritance = 'inheritance',
+}
+
+export interface KeyHistoryEntry {
+  keyHash: number[];
+  algorithm: Algorithm;
+  activatedAt: BN;
+  retiredAt: BN;
+  reason: Record<string, {}>;
+}
+
+/** Ring buffer of retired vault keys; `head` is where the next entry is written. */
+export interface KeyHistoryAccount {
+  vault: PublicKey;
+  head: number;
+  totalRecorded: BN;
+  entries: KeyHistoryEntry[];
+  bump: number;
+}
+
+/** A period during which one key was the vault key; `retiredAt` is null for the current key. */
+export interface KeyPeriod {
+  keyHash: Buffer;
+  algorithm: Algorithm;
+  activatedAt: number;
+  retiredAt: number | null;
+  reason: KeyRetirementReason | null;
+}
+
+/** Beneficiary able to claim the vault after `inactivityPeriod` seconds without activity. */
+export interface InheritanceConfig {
+  beneficiary: PublicKey;
+  algorithm: Algorithm;
+  keyHash: number[];
+  inactivityPeriod: BN;
+  claimDelay: BN;
+}
+
+export interface InheritanceClaimAccount {
+  vault: PublicKey;
+  beneficiary: PublicKey;
+  startedAt: BN;
+  unlockAt: BN;
+  activityCount: BN;
+  bump: number;
+}
+
+/**
+ * `maxAmount` caps the `session_withdraw` instruction only: programs in
+ * `allowedPrograms` get the vault as an unmetered signer through `session_execute`.
+ */
+export interface SessionScope {
+  allowedPrograms: PublicKey[];
+  maxAmount: BN;
+  expirySlot: BN;
+}
+
+/** Delegated key acting for a vault within `scope`; `spent` counts withdrawn base units. */
+export interface SessionGrantAccount {
+  vault: PublicKey;
+  sessionKey: { ed25519: [PublicKey] } | { postQuantum: { algorithm: Algorithm; publicKey: Buffer } };
+  scope: SessionScope;
+  spent: BN;
+  ownerEpoch: number;
+  createdAt: BN;
+  bump: number;
+}
+
+/** Approved withdrawal destination; for SPL mints, the receiving token account. */
+export interface AddressBookEntry {
+  destination: PublicKey;
+  mint: PublicKey | null;
+  cap: BN | null;
+  activeAt: BN;
+}
+
+export interface AddressBookAccount {
+  vault: PublicKey;
+  entries: AddressBookEntry[];
+  bump: number;
+}
+
+/** At most `maxAmount` of `mint` (null for SOL) may leave the vault per `period` seconds. */
+export interface SpendingLimitConfig {
+  mint: PublicKey | null;
+  period: BN;
+  maxAmount: BN;
+}
+
+/** Usage drains linearly at `maxAmount / period`, approximating a rolling window. */
+export interface SpendingLimit {
+  config: SpendingLimitConfig;
+  spent: BN;
+  updatedAt: BN;
+}
+
+/** Withdrawals of `mint` (null for SOL) of at least `minAmount` wait `delay` seconds. */
+export interface WithdrawalTier {
+  mint: PublicKey | null;
+  minAmount: BN;
+  delay: BN;
+}
+
+export interface PendingWithdrawalAccount {
+  vault: PublicKey;
+  action: any;
+  payer: PublicKey;
+  unlockAt: BN;
+  createdAt: BN;
+  overLimit: boolean;
+  ownerEpoch: number;
+  bump: number;
+}
+
+/** Guardians able to replace a lost PQ key; an empty list disables recovery. */
+export interface RecoveryConfig {
+  guardians: PublicKey[];
+  threshold: number;
+  delay: BN;
+}
+
+export interface RecoveryProposalAccount {
+  vault: PublicKey;
+  proposer: PublicKey;
+  newKeyHash: number[];
+  guardiansHash: number[];
+  approvals: number;
+  approvalCount: number;
+  unlockAt: BN | null;
+  expiresAt: BN;
+  createdAt: BN;
+  bump: number;
+}
+
+export interface RotationPolicy {
+  period: BN;
+  gracePeriod: BN;
+}
+
+export interface VaultRegistryAccount {
+  owner: PublicKey;
+  vaultCount: BN;
+  bump: number;
+}
+
+export interface EncryptionKeyRecordAccount {
+  vault: PublicKey;
+  algorithm: Algorithm;
+  publicKey: Buffer;
+  updatedAt: BN;
+  bump: number;
+}
+
+export interface GlobalStateAccount {
+  authority: PublicKey;
+  totalVaults: BN;
+  totalTransactions: BN;
+  bump: number;
+  pendingAuthority: PublicKey | null;
+  paused: boolean;
+  minSecurityLevel: number;
+  allowedAlgorithms: number;
 }
 
 export interface ComplianceStatus {
@@ -35,8 +233,13 @@ export interface ComplianceStatus {
 
 export interface VaultConfig {
   algorithm: Algorithm;
+  signaturePolicy?: SignaturePolicy;
+  label?: string;
   autoRotate?: boolean;
+  /** Seconds between required key rotations (7 to 365 days, default 90). */
   rotationPeriod?: number;
+  /** Seconds before the deadline during which signing emits warnings (default 7 days). */
+  rotationGracePeriod?: number;
 }
 
 export class QuantumCustodyError extends Error {
@@ -1,29 +1,52 @@
 import { PublicKey } from '@solana/web3.js';
-import { Algorithm, QuantumVaultAccount } from './types';
+import { Algorithm, QuantumVaultAccount, SignaturePolicy } from './types';
 
 export class QuantumVault {
   public address: PublicKey;
   public owner: PublicKey;
+  public creator: PublicKey;
+  public pendingOwner: PublicKey | null;
   public algorithm: Algorithm;
   public publicKey: Buffer;
   public createdAt: Date;
   public lastKeyRotation: Date;
   public transactionCount: number;
+  public nonce: number;
+  public signaturePolicy: SignaturePolicy;
+  public vaultIndex: number;
+  public label: string;
+  public rotationPeriod: number;
+  public rotationGracePeriod: number;
 
   constructor(account: QuantumVaultAccount, address: PublicKey) {
     this.address = address;
     this.owner = account.owner;
+    this.creator = account.creator;
+    this.pendingOwner = account.pendingOwner;
     this.algorithm = account.algorithm;
     this.publicKey = Buffer.from(account.publicKey);
     this.createdAt = new Date(account.createdAt.toNumber() * 1000);
     this.lastKeyRotation = new Date(account.lastKeyRotation.toNumber() * 1000);
     this.transactionCount = account.transactionCount.toNumber();
+    this.nonce = account.nonce.toNumber();
+    this.signaturePolicy = account.signaturePolicy;
+    this.vaultIndex = account.vaultIndex.toNumber();
+    this.label = account.label;
+    this.rotationPeriod = account.rotationPolicy.period.toNumber();
+    this.rotationGracePeriod = account.rotationPolicy.gracePeriod.toNumber();
   }
 
   isKeyRotationRequired(): boolean {
     const now = Date.now();
-    const rotationPeriod = 90 * 24 * 60 * 60 * 1000;
-    return now - this.lastKeyRotation.getTime() > rotationPeriod;
+    return now - this.lastKeyRotation.getTime() > this.rotationPeriod * 1000;
+  }
+
+  isKeyRotationDueSoon(): boolean {
+    const elapsed = Date.now() - this.lastKeyRotation.getTime();
+    return (
+      elapsed <= this.rotationPeriod * 1000 &&
+      elapsed > (this.rotationPeriod - this.rotationGracePeriod) * 1000
+    );
   }
 
   getAlgorithmName(): string {